@@ -20,7 +20,7 @@ pub(crate) struct OpendictQuery {
     pub(crate) category: Vec<Category>,
 }
 
-#[must_use]
+#[must_use = "검색 결과를 저장하거나 확인해야 합니다."]
 pub(crate) async fn search_opendict(
     query: &OpendictQuery,
 ) -> Result<(OpendictResult, Vec<OpendictData>), ()> {
@@ -38,7 +38,7 @@ pub(crate) async fn search_opendict(
     let pos = if pos.is_empty() {
         "0".to_owned()
     } else {
-        pos.into_iter()
+        pos.iter()
             .map(|x| format!("{}", *x as u8))
             .collect::<Vec<_>>()
             .join(",")
@@ -47,7 +47,7 @@ pub(crate) async fn search_opendict(
         "0".to_owned()
     } else {
         region
-            .into_iter()
+            .iter()
             .map(|x| format!("{}", *x as u8))
             .collect::<Vec<_>>()
             .join(",")
@@ -56,7 +56,7 @@ pub(crate) async fn search_opendict(
         "0".to_owned()
     } else {
         category
-            .into_iter()
+            .iter()
             .map(|x| format!("{}", *x as u8))
             .collect::<Vec<_>>()
             .join(",")
@@ -127,6 +127,12 @@ fn string_to_result(s: impl AsRef<str>) -> Result<(OpendictResult, Vec<OpendictD
             .ok_or(())?;
         let r#type = sense["type"].as_str().ok_or(())?.to_owned();
         let pos = sense["pos"].as_str().ok_or(())?.to_owned();
+        if let Err(e) = pos.parse::<Pos>() {
+            tracing::warn!("{} ({})", e, code);
+        }
+        if let Err(e) = r#type.parse::<WordType>() {
+            tracing::warn!("{} ({})", e, code);
+        }
         let origin = sense["origin"].as_str().map(|x| x.to_owned());
         for key in sense.as_object().ok_or(())?.keys() {
             if !matches!(
@@ -199,6 +205,175 @@ pub(crate) enum Pos {
     대명사관형사,
     품사없음,
 }
+impl Pos {
+    pub(crate) const ALL: [Pos; 27] = [
+        Pos::명사,
+        Pos::대명사,
+        Pos::수사,
+        Pos::조사,
+        Pos::동사,
+        Pos::형용사,
+        Pos::관형사,
+        Pos::부사,
+        Pos::감탄사,
+        Pos::접사,
+        Pos::의존명사,
+        Pos::보조동사,
+        Pos::보조형용사,
+        Pos::어미,
+        Pos::관형사명사,
+        Pos::수사관형사,
+        Pos::명사부사,
+        Pos::감탄사명사,
+        Pos::대명사부사,
+        Pos::대명사감탄사,
+        Pos::동사형용사,
+        Pos::관형사감탄사,
+        Pos::부사감탄사,
+        Pos::의존명사조사,
+        Pos::수사관형사명사,
+        Pos::대명사관형사,
+        Pos::품사없음,
+    ];
+
+    /// 오픈사전 API가 반환하는 품사 문자열
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Pos::명사 => "명사",
+            Pos::대명사 => "대명사",
+            Pos::수사 => "수사",
+            Pos::조사 => "조사",
+            Pos::동사 => "동사",
+            Pos::형용사 => "형용사",
+            Pos::관형사 => "관형사",
+            Pos::부사 => "부사",
+            Pos::감탄사 => "감탄사",
+            Pos::접사 => "접사",
+            Pos::의존명사 => "의존 명사",
+            Pos::보조동사 => "보조 동사",
+            Pos::보조형용사 => "보조 형용사",
+            Pos::어미 => "어미",
+            Pos::관형사명사 => "관·명",
+            Pos::수사관형사 => "수·관",
+            Pos::명사부사 => "명·부",
+            Pos::감탄사명사 => "감·명",
+            Pos::대명사부사 => "대·부",
+            Pos::대명사감탄사 => "대·감",
+            Pos::동사형용사 => "동·형",
+            Pos::관형사감탄사 => "관·감",
+            Pos::부사감탄사 => "부·감",
+            Pos::의존명사조사 => "의명·조",
+            Pos::수사관형사명사 => "수·관·명",
+            Pos::대명사관형사 => "대·관",
+            Pos::품사없음 => "",
+        }
+    }
+
+    /// 체언(명사, 대명사, 수사)으로 쓰일 수 있는 품사인지 확인합니다.
+    pub(crate) fn is_nominal(&self) -> bool {
+        matches!(
+            self,
+            Pos::명사
+                | Pos::대명사
+                | Pos::수사
+                | Pos::의존명사
+                | Pos::관형사명사
+                | Pos::수사관형사
+                | Pos::명사부사
+                | Pos::감탄사명사
+                | Pos::대명사부사
+                | Pos::대명사감탄사
+                | Pos::의존명사조사
+                | Pos::수사관형사명사
+                | Pos::대명사관형사
+        )
+    }
+
+    /// 오픈사전 품사 문자열(관·명)이나 열거형 이름(관형사명사)으로 품사를 찾습니다.
+    pub(crate) fn from_name(s: &str) -> Result<Pos, UnknownPos> {
+        s.parse().or_else(|e| {
+            Pos::ALL
+                .into_iter()
+                .find(|pos| format!("{:?}", pos) == s)
+                .ok_or(e)
+        })
+    }
+}
+impl std::fmt::Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for Pos {
+    type Err = UnknownPos;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pos::ALL
+            .into_iter()
+            .find(|pos| pos.as_str() == s)
+            .ok_or_else(|| UnknownPos(s.to_owned()))
+    }
+}
+/// 알 수 없는 품사 문자열
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnknownPos(pub(crate) String);
+impl std::fmt::Display for UnknownPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "알 수 없는 품사입니다: \"{}\"", self.0)
+    }
+}
+impl std::error::Error for UnknownPos {}
+/// 단어 유형
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub(crate) enum WordType {
+    일반어 = 1,
+    북한어,
+    방언,
+    옛말,
+}
+impl WordType {
+    pub(crate) const ALL: [WordType; 4] = [
+        WordType::일반어,
+        WordType::북한어,
+        WordType::방언,
+        WordType::옛말,
+    ];
+
+    /// 오픈사전 API가 반환하는 단어 유형 문자열
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            WordType::일반어 => "일반어",
+            WordType::북한어 => "북한어",
+            WordType::방언 => "방언",
+            WordType::옛말 => "옛말",
+        }
+    }
+}
+impl std::fmt::Display for WordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for WordType {
+    type Err = UnknownWordType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WordType::ALL
+            .into_iter()
+            .find(|x| x.as_str() == s)
+            .ok_or_else(|| UnknownWordType(s.to_owned()))
+    }
+}
+/// 알 수 없는 단어 유형 문자열
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnknownWordType(pub(crate) String);
+impl std::fmt::Display for UnknownWordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "알 수 없는 단어 유형입니다: \"{}\"", self.0)
+    }
+}
+impl std::error::Error for UnknownWordType {}
 /// 방언 지역
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -344,8 +519,8 @@ fn get_next_word_query(query: OpendictQuery) -> OpendictQuery {
     let mut query = query;
     query.page = 1;
     let now = query.keyword.chars().next().unwrap();
-    if now == '힣' {
-        query.keyword = "가".to_owned();
+    if now == crate::hangul::SYLLABLE_LAST {
+        query.keyword = crate::hangul::SYLLABLE_FIRST.to_string();
     } else {
         let next = now as u32 + 1;
         query.keyword = std::char::from_u32(next).unwrap().to_string();
@@ -355,6 +530,30 @@ fn get_next_word_query(query: OpendictQuery) -> OpendictQuery {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn test_pos_str_round_trip() {
+        for pos in super::Pos::ALL {
+            assert_eq!(pos.as_str().parse(), Ok(pos));
+        }
+        assert_eq!("명·부".parse(), Ok(super::Pos::명사부사));
+        assert_eq!("보조 형용사".parse(), Ok(super::Pos::보조형용사));
+        assert_eq!("".parse(), Ok(super::Pos::품사없음));
+        assert!("명부".parse::<super::Pos>().is_err());
+    }
+    #[test]
+    fn test_word_type_str_round_trip() {
+        for word_type in super::WordType::ALL {
+            assert_eq!(word_type.as_str().parse(), Ok(word_type));
+        }
+        let err = "표준어".parse::<super::WordType>().unwrap_err();
+        assert_eq!(err, super::UnknownWordType("표준어".to_owned()));
+        assert_eq!(err.to_string(), "알 수 없는 단어 유형입니다: \"표준어\"");
+        let data = super::v1::OpendictData {
+            r#type: "표준어".to_owned(),
+            ..super::v1::OpendictData::test("고양이", "명사")
+        };
+        assert_eq!(data.get_type(), Err(err));
+    }
     #[tokio::test]
     async fn test_search() -> Result<(), Box<dyn std::error::Error>> {
         crate::prelude::init();
@@ -19,3 +19,30 @@ pub(crate) struct OpendictData {
     pub(crate) pos: String,
     pub(crate) origin: Option<String>,
 }
+impl OpendictData {
+    /// 저장된 품사 문자열을 [`Pos`](super::Pos)로 변환합니다.
+    pub(crate) fn get_pos(&self) -> Result<super::Pos, super::UnknownPos> {
+        self.pos.parse()
+    }
+
+    /// 저장된 단어 유형 문자열을 [`WordType`](super::WordType)으로 변환합니다.
+    pub(crate) fn get_type(&self) -> Result<super::WordType, super::UnknownWordType> {
+        self.r#type.parse()
+    }
+}
+#[cfg(test)]
+impl OpendictData {
+    /// 테스트용 일반어 항목, 코드나 뜻풀이 등은 구조체 갱신 문법으로 채웁니다.
+    pub(crate) fn test(word: &str, pos: &str) -> Self {
+        Self {
+            syntactic_annotation: None,
+            syntactic_argument: None,
+            word: word.to_owned(),
+            definition: String::new(),
+            code: 0,
+            r#type: "일반어".to_owned(),
+            pos: pos.to_owned(),
+            origin: None,
+        }
+    }
+}
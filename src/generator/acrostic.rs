@@ -0,0 +1,200 @@
+//! 삼행시
+//!
+//! 주어진 말의 음절마다 그 음절로 시작하는 단어를 골라 한 줄로 잇습니다. (고양이: 고운 양털 이불)
+//! 마지막 단어는 명사, 그 앞은 관형어, 나머지는 부사어를 우선 골라 자연스럽게 읽히도록 합니다.
+
+use crate::{
+    data_collector::opendict::{v1::OpendictData, Pos},
+    generator::{
+        conjugation::{self, PredicateKind, Tense},
+        length::Length,
+        opendict::{self, GenerateOption, Nickname, Transformation, Word},
+    },
+    hangul,
+};
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+/// 문장에서 단어가 맡는 역할
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Role {
+    /// 명사, 대명사, 수사
+    체언,
+    /// 관형형으로 바꾼 용언과 관형사
+    관형어,
+    /// 부사
+    부사어,
+}
+
+impl Role {
+    fn of(pos: Pos) -> Option<Self> {
+        if PredicateKind::from_pos(pos).is_some() {
+            return Some(Self::관형어);
+        }
+        match pos {
+            Pos::관형사 | Pos::수사관형사 | Pos::대명사관형사 => Some(Self::관형어),
+            Pos::부사 | Pos::명사부사 | Pos::대명사부사 | Pos::부사감탄사 => {
+                Some(Self::부사어)
+            }
+            pos if pos.is_nominal() => Some(Self::체언),
+            _ => None,
+        }
+    }
+
+    /// `len`개 단어 중 `index`번째 단어의 역할을 고르는 순서
+    fn preference(index: usize, len: usize) -> [Self; 3] {
+        if index + 1 == len {
+            [Self::체언, Self::부사어, Self::관형어]
+        } else if index + 2 == len {
+            [Self::관형어, Self::체언, Self::부사어]
+        } else {
+            [Self::부사어, Self::관형어, Self::체언]
+        }
+    }
+}
+
+/// 첫 음절별 후보 단어
+pub(crate) struct SyllableIndex<'a> {
+    words: HashMap<(char, Role), Vec<Word<'a>>>,
+}
+
+impl<'a> SyllableIndex<'a> {
+    /// 옵션의 단어 유형, 어종, 단어 길이, 금칙어 조건을 따르는 단어로 색인을 만듭니다.
+    /// 용언은 관형형으로 바꾼 형태의 첫 음절로 색인합니다. (길다 → 긴)
+    pub(crate) fn new(pool: &[&'a OpendictData], option: &GenerateOption) -> Self {
+        let word_range = option.word_length_range();
+        let mut words = HashMap::<_, Vec<_>>::new();
+        for data in option.filter_pool(pool) {
+            let Some(role) = data.get_pos().ok().and_then(Role::of) else {
+                continue;
+            };
+            let kind = data.get_pos().ok().and_then(PredicateKind::from_pos);
+            let (text, transformation) = match kind {
+                Some(kind) => {
+                    let Some(text) = conjugation::to_modifier(
+                        &opendict::normalize(&data.word),
+                        kind,
+                        Tense::현재,
+                    ) else {
+                        continue;
+                    };
+                    (text, Some(Transformation::관형형(Tense::현재)))
+                }
+                None => (opendict::normalize(&data.word), None),
+            };
+            if !text.chars().all(hangul::is_syllable) || !word_range.contains(Length::of(&text)) {
+                continue;
+            }
+            let Some(first) = text.chars().next() else {
+                continue;
+            };
+            words.entry((first, role)).or_default().push(Word {
+                data,
+                text,
+                transformation,
+            });
+        }
+        Self { words }
+    }
+
+    /// `syllable`로 시작하는 단어 중 역할이 `role`인 단어
+    fn get(&self, syllable: char, role: Role) -> &[Word<'a>] {
+        self.words
+            .get(&(syllable, role))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// `input`의 음절마다 그 음절로 시작하는 단어를 골라 띄어 씁니다.
+/// 한글 음절이 아닌 글자는 무시하고, 시작하는 단어가 없는 음절이 있으면 빈 목록을 반환합니다.
+pub(crate) fn generate<'a>(
+    pool: &[&'a OpendictData],
+    input: &str,
+    option: &GenerateOption,
+) -> Vec<Nickname<'a>> {
+    let syllables = input
+        .chars()
+        .filter(|c| hangul::is_syllable(*c))
+        .collect::<Vec<_>>();
+    if syllables.is_empty() {
+        tracing::warn!("삼행시를 지을 한글 음절이 없습니다.");
+        return Vec::new();
+    }
+    let index = SyllableIndex::new(pool, option);
+    // 음절마다 역할을 우선순위대로 골라 후보 단어를 정합니다.
+    let mut slots = Vec::with_capacity(syllables.len());
+    for (i, syllable) in syllables.iter().enumerate() {
+        let candidates = Role::preference(i, syllables.len())
+            .into_iter()
+            .map(|role| index.get(*syllable, role))
+            .find(|x| !x.is_empty());
+        let Some(candidates) = candidates else {
+            tracing::warn!("\"{}\"(으)로 시작하는 단어가 없습니다.", syllable);
+            return Vec::new();
+        };
+        slots.push(candidates);
+    }
+
+    option.collect(
+        |rng| {
+            let words = slots
+                .iter()
+                .map(|x| x.choose(rng).unwrap().clone())
+                .collect::<Vec<_>>();
+            let text = words
+                .iter()
+                .map(|x| x.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            Some(Nickname {
+                text,
+                words,
+                particles: Vec::new(),
+            })
+        },
+        |x| &x.text,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acrostic() {
+        let items = [
+            ("고양이", "명사"),
+            ("곱다", "형용사"),
+            ("가끔", "부사"),
+            ("양말", "명사"),
+            ("이불", "명사"),
+            ("길다", "형용사"),
+            ("-이", "접사"),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(code, (word, pos))| OpendictData {
+            code: code as u32,
+            ..OpendictData::test(word, pos)
+        })
+        .collect::<Vec<_>>();
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            ..Default::default()
+        };
+        let result = generate(&pool, "고양이", &option);
+        let texts = result.iter().map(|x| x.text.as_str()).collect::<Vec<_>>();
+        // 양으로 시작하는 관형어가 없으므로 명사를 쓰고, 곱다는 관형형으로 바꿉니다.
+        assert_eq!(texts, vec!["고운 양말 이불"]);
+        let result = generate(&pool, "가고 긴", &option);
+        assert_eq!(result[0].text, "가끔 고운 긴");
+        assert_eq!(
+            result[0].words[1].transformation,
+            Some(Transformation::관형형(Tense::현재))
+        );
+        assert!(generate(&pool, "강", &option).is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+//! 두 단어를 겹쳐 만드는 혼성어
+//!
+//! 앞 단어의 끝과 뒤 단어의 시작이 같은 자모를 겹쳐 이어붙입니다. (바다 + 닭 = 바닭)
+
+use crate::{
+    data_collector::opendict::{v1::OpendictData, Pos},
+    generator::opendict::{self, GenerateOption, Nickname, Transformation},
+    hangul,
+};
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+/// 겹칠 최소 자모 수
+const MIN_OVERLAP: usize = 2;
+/// 겹칠 최대 자모 수
+const MAX_OVERLAP: usize = 6;
+
+/// 재료 단어
+struct Word<'a> {
+    data: &'a OpendictData,
+    /// 띄어쓰기 표시 등을 뺀 형태
+    text: String,
+    /// 풀어쓴 자모
+    jamo: Vec<char>,
+}
+
+impl<'a> Word<'a> {
+    fn new(data: &'a OpendictData) -> Option<Self> {
+        let text = opendict::normalize(&data.word);
+        if text.is_empty() || !text.chars().all(hangul::is_syllable) {
+            return None;
+        }
+        let jamo = hangul::decompose_str(&text).chars().collect();
+        Some(Self { data, text, jamo })
+    }
+}
+
+/// 앞 단어와 뒤 단어의 품사로 혼성어를 만듭니다.
+/// 뒤 단어에는 앞 단어와 겹친 자모 수를 [`Transformation::혼성`]으로 남깁니다.
+pub(crate) fn generate<'a>(
+    pool: &[&'a OpendictData],
+    front: Pos,
+    back: Pos,
+    option: &GenerateOption,
+) -> Vec<Nickname<'a>> {
+    let words = |query: Pos| {
+        let pool = pool
+            .iter()
+            .copied()
+            .filter(|x| x.get_pos().is_ok_and(|pos| pos == query))
+            .collect::<Vec<_>>();
+        option
+            .filter_pool(&pool)
+            .into_iter()
+            .filter_map(Word::new)
+            .collect::<Vec<_>>()
+    };
+    let fronts = words(front);
+    let backs = words(back);
+
+    // 뒤 단어의 앞부분 자모별 단어
+    let mut prefixes = HashMap::<&[char], Vec<usize>>::new();
+    for (index, word) in backs.iter().enumerate() {
+        for len in MIN_OVERLAP..=MAX_OVERLAP.min(word.jamo.len() - 1) {
+            prefixes.entry(&word.jamo[..len]).or_default().push(index);
+        }
+    }
+    // 겹칠 수 있는 (앞 단어, 겹칠 자모 수)
+    let mut overlaps = Vec::new();
+    for (index, word) in fronts.iter().enumerate() {
+        for len in MIN_OVERLAP..=MAX_OVERLAP.min(word.jamo.len().saturating_sub(1)) {
+            if prefixes.contains_key(&word.jamo[word.jamo.len() - len..]) {
+                overlaps.push((index, len));
+            }
+        }
+    }
+    if overlaps.is_empty() {
+        tracing::warn!("겹칠 수 있는 단어가 없습니다.");
+        return Vec::new();
+    }
+
+    option.collect(
+        |rng| {
+            let (front, len) = overlaps.choose_weighted(rng, |x| x.1).unwrap();
+            let front = &fronts[*front];
+            let back = prefixes[&front.jamo[front.jamo.len() - len..]]
+                .choose(rng)
+                .map(|x| &backs[*x])
+                .unwrap();
+            if front.data.code == back.data.code {
+                return None;
+            }
+            Some(Nickname {
+                text: merge(front, back, *len)?,
+                words: vec![
+                    opendict::Word {
+                        data: front.data,
+                        text: front.text.clone(),
+                        transformation: None,
+                    },
+                    opendict::Word {
+                        data: back.data,
+                        text: back.text.clone(),
+                        transformation: Some(Transformation::혼성(*len)),
+                    },
+                ],
+                particles: Vec::new(),
+            })
+        },
+        |x| &x.text,
+    )
+}
+
+/// 앞 단어 끝의 자모 `len`개와 뒤 단어 앞의 자모를 겹쳐 이어붙입니다.
+/// 이어붙인 자모가 온전한 음절로 모이지 않거나 두 단어 중 하나와 같아지면 `None`을 반환합니다.
+fn merge(front: &Word, back: &Word, len: usize) -> Option<String> {
+    let jamo = front
+        .jamo
+        .iter()
+        .chain(&back.jamo[len..])
+        .collect::<String>();
+    let text = hangul::compose_str(&jamo);
+    let is_clean = text.chars().all(hangul::is_syllable) && hangul::decompose_str(&text) == jamo;
+    (is_clean && text != front.text && text != back.text).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blend() {
+        let items = ["바다", "고양이", "닭", "이름", "다람쥐", "양말"]
+            .into_iter()
+            .enumerate()
+            .map(|(code, word)| OpendictData {
+                code: code as u32,
+                ..OpendictData::test(word, "명사")
+            })
+            .collect::<Vec<_>>();
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            ..Default::default()
+        };
+        let blends = generate(&pool, Pos::명사, Pos::명사, &option);
+        let texts = blends.iter().map(|x| x.text.as_str()).collect::<Vec<_>>();
+        assert!(texts.contains(&"바닭"));
+        assert!(texts.contains(&"바다람쥐"));
+        assert!(texts.contains(&"고양이름"));
+        for blend in &blends {
+            let Some(Transformation::혼성(overlap)) = blend.words[1].transformation else {
+                panic!("뒤 단어에 겹친 자모 수가 없습니다: {:?}", blend);
+            };
+            assert!((MIN_OVERLAP..=MAX_OVERLAP).contains(&overlap));
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! 금칙어 필터
+//!
+//! 단어 경계를 넘어 만들어지는 금칙어와 띄어쓰기, 기호, 풀어쓴 자모로 바꾼 금칙어도 찾습니다.
+
+use crate::{data_collector::opendict::v1::OpendictData, hangul};
+use serde::Deserialize;
+
+/// 금칙어 목록
+///
+/// JSON 파일로 작성합니다.
+/// ```json
+/// {
+///     "words": ["바보"],
+///     "senses": ["낮잡아 이르는"]
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(from = "BlocklistFile")]
+pub(crate) struct Blocklist {
+    /// 닉네임에 들어가면 안 되는 말과 비교할 수 있게 풀어쓴 형태
+    words: Vec<(String, Vec<char>)>,
+    /// 재료 단어의 뜻풀이에 들어가면 안 되는 말과 공백을 뺀 형태
+    senses: Vec<(String, String)>,
+}
+
+/// 금칙어 목록 파일의 형식
+#[derive(Deserialize)]
+struct BlocklistFile {
+    #[serde(default)]
+    words: Vec<String>,
+    /// 생략하면 기본 목록을 씁니다.
+    #[serde(default = "default_senses")]
+    senses: Vec<String>,
+}
+
+/// 비속어, 낮춤말을 나타내는 뜻풀이 표현
+fn default_senses() -> Vec<String> {
+    ["속되게 이르는", "낮잡아 이르는", "욕하여 이르는", "비속어"]
+        .map(String::from)
+        .to_vec()
+}
+
+impl From<BlocklistFile> for Blocklist {
+    fn from(file: BlocklistFile) -> Self {
+        Self::new(file.words, file.senses)
+    }
+}
+
+impl Default for Blocklist {
+    fn default() -> Self {
+        Self::new(Vec::new(), default_senses())
+    }
+}
+
+/// 걸러진 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Blocked {
+    /// 금칙어가 들어 있음
+    Word(String),
+    /// 재료 단어의 뜻풀이에 금지된 표현이 있음 (단어, 표현)
+    Sense(String, String),
+}
+
+impl std::fmt::Display for Blocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Blocked::Word(word) => write!(f, "금칙어가 들어 있습니다: {}", word),
+            Blocked::Sense(word, sense) => {
+                write!(f, "\"{}\"의 뜻풀이에 \"{}\"이/가 있습니다.", word, sense)
+            }
+        }
+    }
+}
+
+impl Blocklist {
+    /// 금칙어와 뜻풀이 표현을 확인할 때마다 바꾸지 않도록 비교할 형태로 미리 바꿔 둡니다.
+    pub(crate) fn new(words: Vec<String>, senses: Vec<String>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|word| {
+                    let normalized = normalize(&word);
+                    (word, normalized)
+                })
+                .collect(),
+            senses: senses
+                .into_iter()
+                .map(|sense| {
+                    let compact = sense.split_whitespace().collect();
+                    (sense, compact)
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn load(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /// 문자열에 금칙어가 있는지 확인합니다.
+    pub(crate) fn check_text(&self, text: &str) -> Result<(), Blocked> {
+        let text = normalize(text);
+        match self.words.iter().find(|(_, word)| contains(&text, word)) {
+            Some((word, _)) => Err(Blocked::Word(word.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// 단어와 단어의 뜻풀이를 확인합니다.
+    pub(crate) fn check_word(&self, data: &OpendictData) -> Result<(), Blocked> {
+        self.check_text(&data.word)?;
+        let definition = data.definition.split_whitespace().collect::<String>();
+        let sense = self
+            .senses
+            .iter()
+            .find(|(_, sense)| definition.contains(sense.as_str()));
+        match sense {
+            Some((sense, _)) => Err(Blocked::Sense(data.word.clone(), sense.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 비교할 수 있도록 공백과 기호를 지우고, 자모를 모아쓴 다음 다시 자모로 풀어씁니다.
+fn normalize(s: &str) -> Vec<char> {
+    let s = s
+        .chars()
+        .map(hangul::to_compatibility)
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect::<String>();
+    hangul::decompose_str(&hangul::compose_str(&s))
+        .chars()
+        .collect()
+}
+
+/// 풀어쓴 문자열에 금칙어가 있는지 확인합니다.
+/// 금칙어가 받침으로 끝나면, 그 받침이 뒤 음절의 초성으로 쓰인 경우는 제외합니다. (예: 시발 - 시바라)
+fn contains(text: &[char], word: &[char]) -> bool {
+    if word.is_empty() {
+        return false;
+    }
+    let ends_with_consonant = word.last().is_some_and(|c| !hangul::MEDIALS.contains(c));
+    text.windows(word.len()).enumerate().any(|(i, window)| {
+        window == word
+            && !(ends_with_consonant
+                && text
+                    .get(i + word.len())
+                    .is_some_and(|c| hangul::MEDIALS.contains(c)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_text() {
+        let blocklist =
+            Blocklist::new(vec!["바보".to_owned(), "멍청".to_owned()], default_senses());
+        assert_eq!(blocklist.check_text("귀여운고양이"), Ok(()));
+        let blocked = Err(Blocked::Word("바보".to_owned()));
+        assert_eq!(blocklist.check_text("착한바보"), blocked);
+        assert_eq!(blocklist.check_text("바 보_123"), blocked);
+        assert_eq!(blocklist.check_text("ㅂㅏ보"), blocked);
+        assert_eq!(blocklist.check_text("ㅂ ㅏ.ㅂ ㅗ"), blocked);
+        // 단어 경계를 넘어 만들어진 금칙어
+        assert_eq!(
+            blocklist.check_text("멍 청소기"),
+            Err(Blocked::Word("멍청".to_owned()))
+        );
+        // 받침이 뒤 음절의 초성이면 다른 말입니다.
+        assert_eq!(blocklist.check_text("멍처어"), Ok(()));
+    }
+
+    #[test]
+    fn test_load() {
+        let blocklist: Blocklist = serde_json::from_str(r#"{"words": ["고 양"]}"#).unwrap();
+        assert_eq!(
+            blocklist,
+            Blocklist::new(vec!["고 양".to_owned()], default_senses())
+        );
+        assert_eq!(
+            blocklist.check_text("고양이"),
+            Err(Blocked::Word("고 양".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_check_word() {
+        let data = OpendictData {
+            definition: "얼굴이 긴 사람을 낮잡아  이르는 말.".to_owned(),
+            ..OpendictData::test("말대가리", "명사")
+        };
+        assert_eq!(
+            Blocklist::default().check_word(&data),
+            Err(Blocked::Sense(
+                "말대가리".to_owned(),
+                "낮잡아 이르는".to_owned()
+            ))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! 용언 활용
+//!
+//! 사전에 저장된 기본형(예쁘다, 달리다)을 명사 앞에 올 수 있는 관형형(예쁜, 달리는)으로 바꿉니다.
+
+use crate::{
+    data_collector::opendict::Pos,
+    hangul::{self, Syllable},
+};
+
+/// 용언 종류
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PredicateKind {
+    동사,
+    형용사,
+}
+
+/// 관형형 시제
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Tense {
+    /// 달리는, 예쁜
+    현재,
+    /// 달린, 예쁘던
+    과거,
+    /// 달릴, 예쁠
+    미래,
+}
+
+impl PredicateKind {
+    /// 품사가 용언이면 용언 종류를 반환합니다.
+    /// 동사와 형용사로 모두 쓰이는 단어는 형용사로 취급합니다.
+    pub(crate) fn from_pos(pos: Pos) -> Option<Self> {
+        match pos {
+            Pos::동사 | Pos::보조동사 => Some(Self::동사),
+            Pos::형용사 | Pos::보조형용사 | Pos::동사형용사 => Some(Self::형용사),
+            _ => None,
+        }
+    }
+}
+
+/// ㅂ 받침이지만 규칙 활용하는 형용사
+const BIEUP_REGULAR_ADJECTIVE: [&str; 2] = ["좁다", "수줍다"];
+/// ㅂ 불규칙 동사
+const BIEUP_IRREGULAR_VERB: [&str; 5] = ["돕다", "눕다", "줍다", "굽다", "깁다"];
+/// ㄷ 불규칙 용언
+const DIGEUT_IRREGULAR: [&str; 8] = [
+    "듣다",
+    "걷다",
+    "묻다",
+    "싣다",
+    "깨닫다",
+    "일컫다",
+    "붇다",
+    "긷다",
+];
+/// ㅅ 불규칙 용언
+const SIOT_IRREGULAR: [&str; 6] = ["짓다", "낫다", "잇다", "붓다", "긋다", "젓다"];
+
+/// 용언 기본형을 관형형으로 바꿉니다.
+/// '다'로 끝나지 않거나 어간이 한글 음절로 끝나지 않으면 `None`을 반환합니다.
+///
+/// 르 불규칙은 관형형 어미에 영향을 주지 않아 규칙 활용과 같이 처리됩니다. (빠르다 → 빠른)
+pub(crate) fn to_modifier(word: &str, kind: PredicateKind, tense: Tense) -> Option<String> {
+    let stem = word.strip_suffix('다')?;
+    let last = stem.chars().last()?;
+    let Syllable { final_, .. } = hangul::decompose(last)?;
+    let with_final = |final_| hangul::with_final(last, final_).unwrap();
+    let head = &stem[..stem.len() - last.len_utf8()];
+
+    // '있다', '없다'로 끝나는 형용사는 동사처럼 '-는'을 붙입니다.
+    let is_exist = last == '있' || last == '없';
+    let ending = match (kind, tense) {
+        (PredicateKind::동사, Tense::현재) => '는',
+        (PredicateKind::형용사, Tense::현재) if is_exist => '는',
+        (PredicateKind::형용사, Tense::과거) => return Some(format!("{}던", stem)),
+        (_, Tense::미래) => '을',
+        _ => '은',
+    };
+
+    let mut result = head.to_owned();
+    match (ending, final_) {
+        // ㄹ 탈락
+        ('는', Some('ㄹ')) => result.extend([with_final(None), '는']),
+        ('는', _) => result.extend([last, '는']),
+        (_, None) => result.push(with_final(Some(attach_final(ending)))),
+        // ㄹ 탈락, 매개모음 '으' 생략
+        (_, Some('ㄹ')) => result.push(with_final(Some(attach_final(ending)))),
+        (_, Some('ㅂ')) if is_bieup_irregular(word, kind) => {
+            result.extend([with_final(None), if ending == '은' { '운' } else { '울' }])
+        }
+        (_, Some('ㄷ')) if is_listed(word, &DIGEUT_IRREGULAR) => {
+            result.extend([with_final(Some('ㄹ')), ending])
+        }
+        (_, Some('ㅅ')) if is_listed(word, &SIOT_IRREGULAR) => {
+            result.extend([with_final(None), ending])
+        }
+        // ㅎ 불규칙, '좋다'는 규칙 활용
+        (_, Some('ㅎ')) if kind == PredicateKind::형용사 && last != '좋' => {
+            result.push(with_final(Some(attach_final(ending))))
+        }
+        _ => result.extend([last, ending]),
+    }
+    Some(result)
+}
+
+/// 매개모음 없이 어간에 붙는 종성
+fn attach_final(ending: char) -> char {
+    if ending == '은' {
+        'ㄴ'
+    } else {
+        'ㄹ'
+    }
+}
+
+fn is_listed(word: &str, list: &[&str]) -> bool {
+    list.iter().any(|x| word.ends_with(x))
+}
+
+fn is_bieup_irregular(word: &str, kind: PredicateKind) -> bool {
+    match kind {
+        PredicateKind::형용사 => !is_listed(word, &BIEUP_REGULAR_ADJECTIVE),
+        PredicateKind::동사 => is_listed(word, &BIEUP_IRREGULAR_VERB),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_modifier, PredicateKind::*, Tense::*};
+
+    #[test]
+    fn test_to_modifier() {
+        let cases = [
+            ("예쁘다", 형용사, 현재, "예쁜"),
+            ("작다", 형용사, 현재, "작은"),
+            ("달리다", 동사, 현재, "달리는"),
+            ("먹다", 동사, 현재, "먹는"),
+            ("재미있다", 형용사, 현재, "재미있는"),
+            ("빠르다", 형용사, 현재, "빠른"),
+            ("예쁘다", 형용사, 과거, "예쁘던"),
+            ("달리다", 동사, 과거, "달린"),
+            ("먹다", 동사, 미래, "먹을"),
+            // ㄹ 탈락
+            ("길다", 형용사, 현재, "긴"),
+            ("살다", 동사, 현재, "사는"),
+            ("살다", 동사, 과거, "산"),
+            ("살다", 동사, 미래, "살"),
+            // ㅂ 불규칙
+            ("귀엽다", 형용사, 현재, "귀여운"),
+            ("좁다", 형용사, 현재, "좁은"),
+            ("돕다", 동사, 과거, "도운"),
+            ("잡다", 동사, 과거, "잡은"),
+            ("돕다", 동사, 현재, "돕는"),
+            // ㄷ 불규칙
+            ("듣다", 동사, 과거, "들은"),
+            ("닫다", 동사, 과거, "닫은"),
+            // ㅅ 불규칙
+            ("짓다", 동사, 미래, "지을"),
+            ("웃다", 동사, 과거, "웃은"),
+            // ㅎ 불규칙
+            ("빨갛다", 형용사, 현재, "빨간"),
+            ("하얗다", 형용사, 미래, "하얄"),
+            ("좋다", 형용사, 현재, "좋은"),
+            ("놓다", 동사, 과거, "놓은"),
+        ];
+        for (word, kind, tense, expected) in cases {
+            assert_eq!(
+                to_modifier(word, kind, tense).as_deref(),
+                Some(expected),
+                "{}",
+                word
+            );
+        }
+        assert_eq!(to_modifier("고양이", 형용사, 현재), None);
+        assert_eq!(to_modifier("다", 형용사, 현재), None);
+    }
+}
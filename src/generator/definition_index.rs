@@ -0,0 +1,119 @@
+//! 뜻풀이 역색인
+//!
+//! 뜻풀이를 어절로 나누고 어절과 조사를 뗀 앞말마다 단어 코드를 모아 sled에 저장합니다.
+//! "바다"로 찾으면 "바다에서", "바다의"처럼 조사가 붙은 어절도 찾지만, "별"로 "별도"는 찾지 않습니다.
+
+use crate::{
+    data_collector::opendict::v1::OpendictData,
+    generator::{opendict, particle},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// 색인 형식이 바뀌면 올려서 저장된 색인을 다시 만들게 합니다.
+const VERSION: u32 = 1;
+
+/// 저장된 색인을 만들 때의 정보, 지금과 다르면 색인을 다시 만듭니다.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct IndexMeta {
+    version: u32,
+    item_revision: u64,
+}
+
+/// 뜻풀이를 글자와 숫자로 이루어진 어절로 나눕니다.
+pub(crate) fn tokenize(s: &str) -> impl Iterator<Item = String> + '_ {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|x| !x.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// 어절을 찾을 수 있는 색인 키 (바다에서: 바다에서, 바다)
+/// 어절이 그대로 표제어이면 조사를 떼지 않습니다. (별도: 별도)
+fn keys<'a>(token: &'a str, headwords: &HashSet<String>) -> impl Iterator<Item = &'a str> {
+    let stem = particle::strip_particle(token).filter(|_| !headwords.contains(token));
+    [token].into_iter().chain(stem)
+}
+
+/// 어절과 조사를 뗀 앞말별 단어 코드를 만듭니다.
+pub(crate) fn build(items: &[OpendictData]) -> BTreeMap<String, Vec<u32>> {
+    let headwords = items
+        .iter()
+        .map(|x| opendict::normalize(&x.word))
+        .collect::<HashSet<_>>();
+    let mut index = BTreeMap::<String, Vec<u32>>::new();
+    for data in items {
+        for token in tokenize(&data.definition) {
+            for key in keys(&token, &headwords) {
+                let codes = index.entry(key.to_owned()).or_default();
+                if codes.last() != Some(&data.code) {
+                    codes.push(data.code);
+                }
+            }
+        }
+    }
+    for codes in index.values_mut() {
+        codes.sort();
+        codes.dedup();
+    }
+    index
+}
+
+/// 저장된 색인이 사전과 맞지 않으면 다시 만들어 저장합니다.
+pub(crate) fn update() {
+    let meta = IndexMeta {
+        version: VERSION,
+        item_revision: crate::prelude::get_opendict_item_revision(),
+    };
+    if crate::prelude::get_definition_index_meta() == Some(meta) {
+        return;
+    }
+    tracing::info!("뜻풀이 색인을 만듭니다.");
+    let items = crate::prelude::get_opendict_items();
+    crate::prelude::set_definition_index(&build(&items), meta);
+}
+
+/// 뜻풀이에 검색어의 모든 어절이 들어 있는 단어 코드를 찾습니다.
+/// 어절은 그대로 나오거나 조사만 붙어 나와야 합니다.
+/// 색인은 [`update`]로 미리 만들어야 합니다.
+pub(crate) fn search(keyword: &str) -> HashSet<u32> {
+    let mut result: Option<HashSet<u32>> = None;
+    for token in tokenize(keyword) {
+        let codes = crate::prelude::get_definition_index(&token).unwrap_or_default();
+        let codes = codes.into_iter().collect::<HashSet<_>>();
+        result = Some(match result {
+            None => codes,
+            Some(result) => result.intersection(&codes).copied().collect(),
+        });
+    }
+    result.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let data = |code: u32, word: &str, definition: &str| OpendictData {
+            definition: definition.to_owned(),
+            code,
+            ..OpendictData::test(word, "명사")
+        };
+        let items = [
+            data(1, "소금", "바다에서 나는 소금."),
+            data(2, "별", "밤하늘의 별."),
+            data(3, "수평선", "바다와 하늘이 맞닿은 곳, 바다의 끝."),
+            data(4, "별도", "원래의 것에 덧붙여서 추가한 것."),
+            data(5, "별채", "별도로 지은 집."),
+            data(6, "따로", "별도 마련한 자리."),
+        ];
+        let index = build(&items);
+        assert_eq!(index["바다"], vec![1, 3]);
+        assert_eq!(index["바다에서"], vec![1]);
+        assert_eq!(index["하늘"], vec![3]);
+        assert!(!index.contains_key("하늘의"));
+        assert_eq!(index["별"], vec![2]);
+        assert_eq!(index["별도"], vec![5, 6]);
+        assert!(!index.contains_key("바"));
+    }
+}
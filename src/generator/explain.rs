@@ -0,0 +1,140 @@
+//! 닉네임에 쓰인 사전 항목 설명
+
+use crate::generator::opendict::Nickname;
+
+/// 닉네임을 이루는 단어와 조사를 한 줄에 하나씩 설명합니다.
+///
+/// ```text
+/// 예쁜 ← 예쁘다 [형용사, 123] 관형형: 생긴 모양이 아름다워 눈으로 보기에 좋다.
+/// 고양이 [명사, 456]: 고양잇과의 하나.
+/// 가 ← 이/가: 앞 말의 받침에 맞춘 조사
+/// 닭 [명사, 789] 앞 말과 자모 2개를 겹침: 꿩과의 새.
+/// 명 ← 명월 [명사, 12] 明의 음: 밝은 달.
+/// ```
+pub(crate) fn to_lines(nickname: &Nickname) -> Vec<String> {
+    let mut lines = Vec::new();
+    for word in &nickname.words {
+        let mut line = word.text.clone();
+        if word.text != word.data.word {
+            line.push_str(&format!(" ← {}", word.data.word));
+        }
+        line.push_str(&format!(" [{}, {}]", word.data.pos, word.data.code));
+        if let Some(transformation) = &word.transformation {
+            line.push_str(&format!(" {}", transformation));
+        }
+        line.push_str(&format!(": {}", word.data.definition));
+        lines.push(line);
+    }
+    for (particle, form) in &nickname.particles {
+        lines.push(format!("{} ← {}: 앞 말의 받침에 맞춘 조사", form, particle));
+    }
+    lines
+}
+
+/// 닉네임을 이루는 단어와 조사를 JSON으로 설명합니다.
+pub(crate) fn to_json(nickname: &Nickname) -> serde_json::Value {
+    let words = nickname
+        .words
+        .iter()
+        .map(|word| {
+            serde_json::json!({
+                "text": word.text,
+                "word": word.data.word,
+                "code": word.data.code,
+                "pos": word.data.pos,
+                "definition": word.data.definition,
+                "transformation": word.transformation.map(|x| x.to_string()),
+            })
+        })
+        .collect::<Vec<_>>();
+    let particles = nickname
+        .particles
+        .iter()
+        .map(|(particle, form)| {
+            serde_json::json!({
+                "text": form,
+                "particle": particle.to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({
+        "words": words,
+        "particles": particles,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_collector::opendict::{v1::OpendictData, Pos},
+        generator::{
+            blend, hanja,
+            opendict::{generate_with_template, GenerateOption},
+        },
+    };
+
+    #[test]
+    fn test_explain() {
+        let items = [("예쁘다", "형용사"), ("고양이", "명사")]
+            .into_iter()
+            .enumerate()
+            .map(|(code, (word, pos))| OpendictData {
+                definition: format!("{}의 뜻.", word),
+                code: code as u32,
+                ..OpendictData::test(word, pos)
+            })
+            .collect::<Vec<_>>();
+        let pool = items.iter().collect::<Vec<_>>();
+        let template = "{형용사}{명사}이/가".parse().unwrap();
+        let nicknames = generate_with_template(&pool, &template, &GenerateOption::default());
+        assert_eq!(nicknames[0].text, "예쁜고양이가");
+        assert_eq!(
+            to_lines(&nicknames[0]),
+            vec![
+                "예쁜 ← 예쁘다 [형용사, 0] 관형형: 예쁘다의 뜻.",
+                "고양이 [명사, 1]: 고양이의 뜻.",
+                "가 ← 이/가: 앞 말의 받침에 맞춘 조사",
+            ]
+        );
+        let json = to_json(&nicknames[0]);
+        assert_eq!(json["words"][0]["transformation"], "관형형");
+        assert_eq!(json["words"][1]["transformation"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_explain_blend_and_hanja() {
+        let data = |code: u32, word: &str, origin: Option<&str>, definition: &str| OpendictData {
+            definition: definition.to_owned(),
+            code,
+            origin: origin.map(str::to_owned),
+            ..OpendictData::test(word, "명사")
+        };
+        let items = [
+            data(0, "바다", None, "바다의 뜻."),
+            data(1, "닭", None, "닭의 뜻."),
+            data(2, "명월", Some("明月"), "밝은 달."),
+        ];
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 1,
+            seed: Some(0),
+            ..Default::default()
+        };
+        let blends = blend::generate(&pool, Pos::명사, Pos::명사, &option);
+        assert_eq!(blends[0].text, "바닭");
+        assert_eq!(
+            to_lines(&blends[0]),
+            vec![
+                "바다 [명사, 0]: 바다의 뜻.",
+                "닭 [명사, 1] 앞 말과 자모 2개를 겹침: 닭의 뜻.",
+            ]
+        );
+        let names = hanja::generate(&pool, &["明".to_owned()], &option);
+        assert_eq!(names[0].text, "명");
+        assert_eq!(
+            to_lines(&names[0]),
+            vec!["명 ← 명월 [명사, 2] 明의 음: 밝은 달."]
+        );
+    }
+}
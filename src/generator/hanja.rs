@@ -0,0 +1,266 @@
+//! 한자 뜻으로 두 글자 이름 짓기
+//!
+//! 한자어의 원어와 단어를 글자 단위로 맞춰 한자별 음과 쓰인 단어를 모읍니다.
+
+use crate::{
+    data_collector::opendict::v1::OpendictData,
+    generator::{
+        opendict::{GenerateOption, Nickname, Transformation, Word},
+        word_class::is_hanja,
+    },
+    hangul,
+};
+use rand::seq::SliceRandom;
+use std::collections::BTreeMap;
+
+/// 뜻마다 이름에 쓸 후보 한자 수
+const CANDIDATES_PER_MEANING: usize = 10;
+
+/// 한자 하나의 정보
+#[derive(Debug, Default)]
+pub(crate) struct HanjaEntry<'a> {
+    /// 단어 첫머리에서의 음과 쓰인 횟수, 두음 법칙이 적용된 음입니다. (良: 양)
+    pub(crate) initial_readings: BTreeMap<char, usize>,
+    /// 단어 첫머리가 아닌 곳에서의 음과 쓰인 횟수 (良: 량)
+    pub(crate) readings: BTreeMap<char, usize>,
+    /// 이 한자가 쓰인 단어
+    pub(crate) words: Vec<&'a OpendictData>,
+}
+
+impl<'a> HanjaEntry<'a> {
+    /// 가장 많이 쓰인 음, 첫머리 음이 없으면 다른 음을 씁니다.
+    pub(crate) fn reading(&self, initial: bool) -> Option<char> {
+        let (first, second) = if initial {
+            (&self.initial_readings, &self.readings)
+        } else {
+            (&self.readings, &self.initial_readings)
+        };
+        let most = |x: &BTreeMap<char, usize>| {
+            x.iter()
+                .max_by_key(|(_, count)| **count)
+                .map(|(reading, _)| *reading)
+        };
+        most(first).or_else(|| most(second))
+    }
+
+    /// 뜻풀이에 키워드가 들어간 단어의 비율과 수로 뜻이 얼마나 가까운지 구합니다.
+    fn score(&self, keyword: &str) -> f64 {
+        let matched = self.matches(keyword).count() as f64;
+        matched * matched / self.words.len() as f64
+    }
+
+    /// 이 한자가 쓰인 단어 중 뜻풀이에 키워드가 들어간 단어
+    fn matches<'b>(&'b self, keyword: &'b str) -> impl Iterator<Item = &'a OpendictData> + 'b {
+        self.words
+            .iter()
+            .copied()
+            .filter(move |x| x.word == keyword || x.definition.contains(keyword))
+    }
+
+    /// 이름에 쓴 한자를 설명할 단어, 뜻이 키워드와 맞는 단어를 먼저 고릅니다.
+    pub(crate) fn source(&self, meaning: &str) -> Option<&'a OpendictData> {
+        self.matches(keyword(meaning))
+            .next()
+            .or_else(|| self.words.first().copied())
+    }
+}
+
+/// 한자 색인
+#[derive(Debug, Default)]
+pub(crate) struct HanjaIndex<'a> {
+    pub(crate) entries: BTreeMap<char, HanjaEntry<'a>>,
+}
+
+impl<'a> HanjaIndex<'a> {
+    pub(crate) fn new(pool: &[&'a OpendictData]) -> Self {
+        let mut index = Self::default();
+        for data in pool {
+            let Some(pairs) = align(data) else {
+                continue;
+            };
+            for (i, (hanja, reading)) in pairs.into_iter().enumerate() {
+                let entry = index.entries.entry(hanja).or_default();
+                let readings = if i == 0 {
+                    &mut entry.initial_readings
+                } else {
+                    &mut entry.readings
+                };
+                *readings.entry(reading).or_default() += 1;
+                if entry.words.last().is_none_or(|x| x.code != data.code) {
+                    entry.words.push(data);
+                }
+            }
+        }
+        index
+    }
+
+    pub(crate) fn get(&self, hanja: char) -> Option<&HanjaEntry<'a>> {
+        self.entries.get(&hanja)
+    }
+
+    /// 뜻에 가까운 한자를 가까운 순으로 찾습니다.
+    /// 한자 한 글자를 적으면 그 한자만 찾습니다.
+    /// `밝다`처럼 `다`로 끝나는 말은 `밝`으로 찾습니다.
+    pub(crate) fn search(&self, meaning: &str) -> Vec<(char, f64)> {
+        let meaning = meaning.trim();
+        let mut chars = meaning.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if is_hanja(c) {
+                return self.get(c).map(|_| (c, 1.0)).into_iter().collect();
+            }
+        }
+        let keyword = keyword(meaning);
+        let mut result = self
+            .entries
+            .iter()
+            .map(|(hanja, entry)| (*hanja, entry.score(keyword)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect::<Vec<_>>();
+        result.sort_by(|a, b| b.1.total_cmp(&a.1));
+        result
+    }
+}
+
+/// 뜻으로 찾을 때 쓰는 키워드, `밝다`처럼 `다`로 끝나는 말은 `밝`으로 찾습니다.
+fn keyword(meaning: &str) -> &str {
+    let meaning = meaning.trim();
+    match meaning.strip_suffix('다') {
+        Some(stem) if !stem.is_empty() => stem,
+        _ => meaning,
+    }
+}
+
+/// 한자어의 원어와 단어를 글자 단위로 맞춥니다. (한자, 음)
+/// `多情하다`처럼 원어 뒤에 한글이 붙어 있으면 단어 뒤의 같은 글자를 떼고 맞춥니다.
+/// 글자 수가 다르면 `None`을 반환합니다.
+fn align(data: &OpendictData) -> Option<Vec<(char, char)>> {
+    let origin = data.origin.as_ref()?;
+    let origin = origin
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .collect::<Vec<_>>();
+    let word = data.word.chars().filter(|c| c.is_alphabetic());
+    let mut word = word.collect::<Vec<_>>();
+    let hanja_len = origin.iter().take_while(|c| is_hanja(**c)).count();
+    let suffix = &origin[hanja_len..];
+    if hanja_len == 0 || !word.ends_with(suffix) {
+        return None;
+    }
+    word.truncate(word.len() - suffix.len());
+    if word.len() != hanja_len || !word.iter().all(|c| hangul::is_syllable(*c)) {
+        return None;
+    }
+    Some(origin[..hanja_len].iter().copied().zip(word).collect())
+}
+
+/// 뜻 목록에서 한자를 하나씩 골라 이름을 짓습니다.
+/// 음절마다 그 한자가 쓰인 단어와 한자를 [`Transformation::한자음`]으로 남깁니다.
+pub(crate) fn generate<'a>(
+    pool: &[&'a OpendictData],
+    meanings: &[String],
+    option: &GenerateOption,
+) -> Vec<Nickname<'a>> {
+    let pool = option.filter_pool(pool);
+    let index = HanjaIndex::new(&pool);
+    let mut candidates = Vec::with_capacity(meanings.len());
+    for (i, meaning) in meanings.iter().enumerate() {
+        let hanja = index
+            .search(meaning)
+            .into_iter()
+            .take(CANDIDATES_PER_MEANING)
+            .filter_map(|(hanja, score)| {
+                let entry = index.get(hanja)?;
+                let word = Word {
+                    data: entry.source(meaning)?,
+                    text: entry.reading(i == 0)?.to_string(),
+                    transformation: Some(Transformation::한자음(hanja)),
+                };
+                Some((word, score))
+            })
+            .collect::<Vec<_>>();
+        if hanja.is_empty() {
+            tracing::warn!("\"{}\"에 해당하는 한자가 없습니다.", meaning);
+            return Vec::new();
+        }
+        candidates.push(hanja);
+    }
+
+    option.collect(
+        |rng| {
+            let words = candidates
+                .iter()
+                .map(|x| x.choose_weighted(&mut *rng, |x| x.1).unwrap().0.clone())
+                .collect::<Vec<_>>();
+            Some(Nickname {
+                text: words.iter().map(|x| x.text.as_str()).collect(),
+                words,
+                particles: Vec::new(),
+            })
+        },
+        |x| &x.text,
+    )
+}
+
+/// 이름에 쓴 한자, 한자음이 아닌 단어는 건너뜁니다. (명월 → 明月)
+pub(crate) fn hanja_of(nickname: &Nickname) -> String {
+    nickname
+        .words
+        .iter()
+        .filter_map(|x| match x.transformation {
+            Some(Transformation::한자음(hanja)) => Some(hanja),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(code: u32, word: &str, origin: &str, definition: &str) -> OpendictData {
+        OpendictData {
+            definition: definition.to_owned(),
+            code,
+            origin: Some(origin.to_owned()),
+            ..OpendictData::test(word, "명사")
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        let items = [
+            data(0, "명월", "明月", "밝은 달."),
+            data(1, "광명", "光明", "밝고 환한 빛."),
+            data(2, "천지", "天地", "하늘과 땅을 아울러 이르는 말."),
+            data(7, "천상", "天上", "하늘 위."),
+            data(3, "양심", "良心", "사물의 가치를 판단하는 도덕적 의식."),
+            data(4, "선량", "善良", "행실이나 성질이 착함."),
+            data(5, "다정하다", "多情하다", "정이 많다."),
+            data(6, "커피", "coffee", "커피나무의 열매."),
+        ];
+        let pool = items.iter().collect::<Vec<_>>();
+        let index = HanjaIndex::new(&pool);
+        assert_eq!(index.get('明').unwrap().words.len(), 2);
+        assert_eq!(index.get('良').unwrap().reading(true), Some('양'));
+        assert_eq!(index.get('良').unwrap().reading(false), Some('량'));
+        assert_eq!(index.get('情').unwrap().reading(false), Some('정'));
+        assert_eq!(index.search("밝다")[0].0, '明');
+        assert_eq!(index.search("하늘")[0].0, '天');
+        assert_eq!(index.search("天"), vec![('天', 1.0)]);
+
+        let option = GenerateOption {
+            count: 1,
+            seed: Some(0),
+            ..Default::default()
+        };
+        let names = generate(&pool, &["밝다".to_owned(), "하늘".to_owned()], &option);
+        assert_eq!(names.len(), 1);
+        let first = hanja_of(&names[0]).chars().next().unwrap();
+        assert!(['明', '月', '光'].contains(&first));
+        assert_eq!(
+            names[0].text.chars().next(),
+            index.get(first).unwrap().reading(true)
+        );
+        assert!(names[0].words[0].data.definition.contains("밝"));
+    }
+}
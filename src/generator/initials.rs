@@ -0,0 +1,322 @@
+//! 초성 패턴
+//!
+//! `ㄱㅇㅇ`처럼 초성으로, `고ㅇ?`처럼 음절, 초성, 아무 음절(`?`)을 섞어 단어를 찾습니다.
+//! 한 단어로 맞지 않으면 패턴을 나눠 여러 단어를 이어붙인 조합도 찾습니다. (ㄱㅇㅇ: 강 + 아이)
+
+use crate::{
+    data_collector::opendict::v1::OpendictData,
+    generator::{
+        length::Length,
+        opendict::{self, GenerateOption, Nickname, Word},
+    },
+    hangul,
+};
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+/// 초성 패턴의 한 글자
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PatternChar {
+    /// 이 음절이어야 함
+    음절(char),
+    /// 이 초성으로 시작하는 음절
+    초성(char),
+    /// 아무 음절 (`?`)
+    아무음절,
+}
+
+impl PatternChar {
+    fn is_match(&self, c: char) -> bool {
+        match self {
+            Self::음절(x) => *x == c,
+            Self::초성(x) => hangul::initial_of(c) == Some(*x),
+            Self::아무음절 => hangul::is_syllable(c),
+        }
+    }
+}
+
+/// 초성 패턴, 공백은 무시합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct InitialPattern {
+    chars: Vec<PatternChar>,
+}
+
+/// 초성 패턴으로 쓸 수 없는 문자열
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct InvalidInitialPattern(pub(crate) String);
+
+impl std::fmt::Display for InvalidInitialPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "초성 패턴에는 한글 음절, 초성, '?'만 쓸 수 있습니다: \"{}\"",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidInitialPattern {}
+
+impl std::str::FromStr for InitialPattern {
+    type Err = InvalidInitialPattern;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match hangul::to_compatibility(c) {
+                '?' => Some(PatternChar::아무음절),
+                c if hangul::is_syllable(c) => Some(PatternChar::음절(c)),
+                c if hangul::INITIALS.contains(&c) => Some(PatternChar::초성(c)),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|x| !x.is_empty())
+            .ok_or_else(|| InvalidInitialPattern(s.to_owned()))?;
+        Ok(Self { chars })
+    }
+}
+
+impl std::fmt::Display for InitialPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for c in &self.chars {
+            match c {
+                PatternChar::음절(c) | PatternChar::초성(c) => write!(f, "{}", c)?,
+                PatternChar::아무음절 => write!(f, "?")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InitialPattern {
+    /// 패턴의 음절 수
+    pub(crate) fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// 문자열의 한글 음절이 패턴과 맞는지 확인합니다. 한글 음절이 아닌 글자는 무시합니다.
+    pub(crate) fn is_match(&self, text: &str) -> bool {
+        let mut syllables = text.chars().filter(|c| hangul::is_syllable(*c));
+        self.chars
+            .iter()
+            .all(|x| syllables.next().is_some_and(|c| x.is_match(c)))
+            && syllables.next().is_none()
+    }
+
+    /// 초성만으로 이루어진 색인 키, `?`가 있으면 `None`
+    fn key(&self) -> Option<String> {
+        self.chars
+            .iter()
+            .map(|x| match x {
+                PatternChar::음절(c) => hangul::initial_of(*c),
+                PatternChar::초성(c) => Some(*c),
+                PatternChar::아무음절 => None,
+            })
+            .collect()
+    }
+
+    fn slice(&self, range: std::ops::Range<usize>) -> Self {
+        Self {
+            chars: self.chars[range].to_vec(),
+        }
+    }
+}
+
+/// 문자열의 한글 음절의 초성
+fn initials(text: &str) -> String {
+    text.chars().filter_map(hangul::initial_of).collect()
+}
+
+/// 표제어의 초성별 단어
+pub(crate) struct InitialIndex<'a> {
+    words: HashMap<String, Vec<Word<'a>>>,
+}
+
+impl<'a> InitialIndex<'a> {
+    /// 띄어쓰기 표시(^)와 붙임표(-)를 뺀 표제어가 한글 음절로만 이루어진 단어로 색인을 만듭니다.
+    pub(crate) fn new(pool: &[&'a OpendictData]) -> Self {
+        let mut words = HashMap::<_, Vec<_>>::new();
+        for data in pool.iter().copied() {
+            let text = opendict::normalize(&data.word);
+            if text.is_empty() || !text.chars().all(hangul::is_syllable) {
+                continue;
+            }
+            words.entry(initials(&text)).or_default().push(Word {
+                data,
+                text,
+                transformation: None,
+            });
+        }
+        Self { words }
+    }
+
+    /// 패턴과 맞는 단어
+    pub(crate) fn find(&self, pattern: &InitialPattern) -> Vec<&Word<'a>> {
+        let words = match pattern.key() {
+            Some(key) => self
+                .words
+                .get(&key)
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>(),
+            None => self
+                .words
+                .iter()
+                .filter(|(key, _)| key.chars().count() == pattern.len())
+                .flat_map(|(_, words)| words)
+                .collect(),
+        };
+        let mut words = words
+            .into_iter()
+            .filter(|x| pattern.is_match(&x.text))
+            .collect::<Vec<_>>();
+        words.sort_by_key(|x| x.data.code);
+        words
+    }
+
+    /// 패턴을 나눠 단어 `max_words`개까지 이어붙일 수 있는 조합을 찾습니다.
+    /// 조합마다 나눈 부분별 후보 단어를 반환하며, 단어 수가 적은 조합이 먼저 옵니다.
+    pub(crate) fn combinations(
+        &self,
+        pattern: &InitialPattern,
+        max_words: usize,
+    ) -> Vec<Vec<Vec<&Word<'a>>>> {
+        let len = pattern.len();
+        let mut found = HashMap::new();
+        let mut find = |start: usize, end: usize| {
+            found
+                .entry((start, end))
+                .or_insert_with(|| self.find(&pattern.slice(start..end)))
+                .clone()
+        };
+        // (다음 시작 위치, 지금까지 나눈 부분별 후보)
+        let mut stack = vec![(0, Vec::new())];
+        let mut result = Vec::new();
+        while let Some((start, parts)) = stack.pop() {
+            if start == len {
+                result.push(parts);
+                continue;
+            }
+            if parts.len() >= max_words {
+                continue;
+            }
+            for end in (start + 1..=len).rev() {
+                let words = find(start, end);
+                if !words.is_empty() {
+                    let mut parts = parts.clone();
+                    parts.push(words);
+                    stack.push((end, parts));
+                }
+            }
+        }
+        result.sort_by_key(|x: &Vec<_>| x.len());
+        result
+    }
+}
+
+/// 초성 패턴과 맞는 단어나 단어 `max_words`개까지의 조합을 이어붙여 닉네임을 만듭니다.
+pub(crate) fn generate<'a>(
+    pool: &[&'a OpendictData],
+    pattern: &InitialPattern,
+    max_words: usize,
+    option: &GenerateOption,
+) -> Vec<Nickname<'a>> {
+    let word_range = option.word_length_range();
+    let pool = option
+        .filter_pool(pool)
+        .into_iter()
+        .filter(|x| word_range.contains(Length::of(&x.word)))
+        .collect::<Vec<_>>();
+    let index = InitialIndex::new(&pool);
+    let combinations = index.combinations(pattern, max_words);
+    if combinations.is_empty() {
+        tracing::warn!("\"{}\"에 맞는 단어 조합이 없습니다.", pattern);
+        return Vec::new();
+    }
+
+    option.collect(
+        |rng| {
+            // 단어 수가 적은 조합일수록 자주 고릅니다.
+            let parts = combinations
+                .choose_weighted(rng, |x| 1.0 / (x.len() * x.len()) as f64)
+                .unwrap();
+            let words = parts
+                .iter()
+                .map(|x| (*x.choose(rng).unwrap()).clone())
+                .collect::<Vec<_>>();
+            Some(Nickname {
+                text: words.iter().map(|x| x.text.as_str()).collect(),
+                words,
+                particles: Vec::new(),
+            })
+        },
+        |x| &x.text,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern() {
+        let pattern = "고ㅇ?".parse::<InitialPattern>().unwrap();
+        assert_eq!(pattern.to_string(), "고ㅇ?");
+        assert!(pattern.is_match("고양이"));
+        assert!(pattern.is_match("고양 이!"));
+        assert!(!pattern.is_match("고양"));
+        assert!(!pattern.is_match("거울이"));
+        assert!("ㄱ a".parse::<InitialPattern>().is_err());
+        assert!("".parse::<InitialPattern>().is_err());
+    }
+
+    #[test]
+    fn test_index() {
+        let items = ["고양이", "거미", "강", "아이", "구름^여우", "-이"]
+            .into_iter()
+            .enumerate()
+            .map(|(code, word)| OpendictData {
+                code: code as u32,
+                ..OpendictData::test(word, "명사")
+            })
+            .collect::<Vec<_>>();
+        let pool = items.iter().collect::<Vec<_>>();
+        let index = InitialIndex::new(&pool);
+        let texts = |pattern: &str| {
+            let pattern = pattern.parse().unwrap();
+            index
+                .find(&pattern)
+                .iter()
+                .map(|x| x.text.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(texts("ㄱㅇㅇ"), vec!["고양이"]);
+        assert_eq!(texts("?ㅇ"), vec!["아이"]);
+        assert_eq!(texts("ㄱㄹㅇㅇ"), vec!["구름여우"]);
+
+        let pattern = "ㄱㅇㅇ".parse().unwrap();
+        let combinations = index.combinations(&pattern, 2);
+        let texts = combinations
+            .iter()
+            .map(|x| x.iter().map(|x| x[0].text.as_str()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec![vec!["고양이"], vec!["강", "아이"]]);
+        // 단어를 세 개까지 이으면 강 + 이 + 이처럼 쓸 수 있는 단어가 늘어납니다.
+        assert_eq!(index.combinations(&pattern, 3).len(), 3);
+
+        let option = GenerateOption {
+            seed: Some(0),
+            ..Default::default()
+        };
+        let nicknames = generate(&pool, &pattern, 1, &option);
+        let texts = nicknames
+            .iter()
+            .map(|x| x.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["고양이"]);
+        assert!(generate(&pool, &pattern, 2, &option)
+            .iter()
+            .all(|x| x.words.len() <= 2));
+    }
+}
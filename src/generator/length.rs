@@ -0,0 +1,94 @@
+//! 닉네임 길이
+//!
+//! 한글 음절 수와 터미널에 표시되는 너비를 함께 다룹니다.
+
+use unicode_width::UnicodeWidthStr;
+
+/// 문자열의 길이
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Length {
+    /// 한글 음절 수
+    pub(crate) syllables: usize,
+    /// 화면 너비, 한글 음절은 2칸입니다.
+    pub(crate) width: usize,
+}
+
+impl Length {
+    pub(crate) fn of(s: &str) -> Self {
+        Self {
+            syllables: s.chars().filter(|c| crate::hangul::is_syllable(*c)).count(),
+            width: s.width(),
+        }
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        Self {
+            syllables: self.syllables.saturating_add(other.syllables),
+            width: self.width.saturating_add(other.width),
+        }
+    }
+}
+
+/// 문자열이 가질 수 있는 길이의 범위
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct LengthRange {
+    pub(crate) min: Length,
+    pub(crate) max: Length,
+}
+
+impl Default for LengthRange {
+    fn default() -> Self {
+        Self::exact(Length::default())
+    }
+}
+
+impl LengthRange {
+    pub(crate) fn exact(length: Length) -> Self {
+        Self {
+            min: length,
+            max: length,
+        }
+    }
+
+    /// 두 문자열을 이어붙였을 때의 범위
+    pub(crate) fn add(self, other: Self) -> Self {
+        Self {
+            min: self.min.saturating_add(other.min),
+            max: self.max.saturating_add(other.max),
+        }
+    }
+
+    /// 두 범위를 모두 포함하는 범위
+    pub(crate) fn union(self, other: Self) -> Self {
+        Self {
+            min: Length {
+                syllables: self.min.syllables.min(other.min.syllables),
+                width: self.min.width.min(other.min.width),
+            },
+            max: Length {
+                syllables: self.max.syllables.max(other.max.syllables),
+                width: self.max.width.max(other.max.width),
+            },
+        }
+    }
+
+    /// 길이 목록을 모두 포함하는 범위, 목록이 비어 있으면 `None`을 반환합니다.
+    pub(crate) fn of_all(lengths: impl IntoIterator<Item = Length>) -> Option<Self> {
+        lengths
+            .into_iter()
+            .map(Self::exact)
+            .reduce(|a, b| a.union(b))
+    }
+
+    /// 두 범위가 겹치는지 확인합니다.
+    pub(crate) fn overlaps(&self, other: &Self) -> bool {
+        self.min.syllables <= other.max.syllables
+            && other.min.syllables <= self.max.syllables
+            && self.min.width <= other.max.width
+            && other.min.width <= self.max.width
+    }
+
+    pub(crate) fn contains(&self, length: Length) -> bool {
+        self.overlaps(&Self::exact(length))
+    }
+}
@@ -0,0 +1,157 @@
+//! 음절 n-gram 마르코프 모형으로 사전에 없는 낱말 만들기
+
+use crate::{
+    data_collector::opendict::v1::OpendictData,
+    generator::{
+        opendict::{self, GenerateOption},
+        usability::Usability,
+    },
+    hangul,
+};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 낱말의 시작을 나타내는 문자
+const START: char = '^';
+/// 낱말의 끝을 나타내는 문자
+const END: char = '$';
+/// 길이 조건이 없을 때 만들 낱말의 음절 수
+const DEFAULT_SYLLABLES: (usize, usize) = (2, 4);
+
+/// 음절 n-gram 모형
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct MarkovModel {
+    /// 다음 음절을 고를 때 보는 앞 음절 수
+    pub(crate) order: usize,
+    /// 학습할 때의 사전 항목 리비전, 사전이 바뀌었는지 확인할 때 씁니다.
+    pub(crate) item_revision: u64,
+    /// 학습한 낱말, 만든 낱말이 사전에 있는지 확인할 때 씁니다.
+    words: BTreeSet<String>,
+    /// 앞 음절별 다음 음절과 나온 횟수
+    transitions: BTreeMap<String, BTreeMap<char, u32>>,
+}
+
+impl MarkovModel {
+    /// 사전 항목으로 모형을 학습합니다.
+    /// 홀로 쓸 수 있고 한글 음절로만 이루어진 두 음절 이상의 단어만 씁니다.
+    pub(crate) fn train<'a>(
+        items: impl IntoIterator<Item = &'a OpendictData>,
+        item_revision: u64,
+        order: usize,
+    ) -> Self {
+        let mut model = Self {
+            order,
+            item_revision,
+            words: BTreeSet::new(),
+            transitions: BTreeMap::new(),
+        };
+        for data in items {
+            if Usability::of(data) != Usability::자립 {
+                continue;
+            }
+            // 오픈사전은 띄어 쓸 수 있는 자리를 ^로 표시합니다.
+            let word = opendict::normalize(&data.word);
+            if word.chars().count() < 2 || !word.chars().all(hangul::is_syllable) {
+                continue;
+            }
+            let mut context = START.to_string().repeat(order);
+            for c in word.chars().chain([END]) {
+                *model
+                    .transitions
+                    .entry(context.clone())
+                    .or_default()
+                    .entry(c)
+                    .or_default() += 1;
+                context.push(c);
+                context.remove(0);
+            }
+            model.words.insert(word);
+        }
+        model
+    }
+
+    /// 사전에 있는 낱말인지 확인합니다.
+    pub(crate) fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+
+    /// 낱말을 하나 만듭니다. 음절 수 조건을 맞추지 못하면 `None`을 반환합니다.
+    pub(crate) fn sample(&self, rng: &mut ChaCha8Rng, min: usize, max: usize) -> Option<String> {
+        let mut word = String::new();
+        let mut context = START.to_string().repeat(self.order);
+        for len in 0.. {
+            let next = self
+                .transitions
+                .get(&context)?
+                .iter()
+                .filter(|(c, _)| match **c {
+                    END => len >= min,
+                    _ => len < max,
+                })
+                .collect::<Vec<_>>();
+            let (c, _) = next.choose_weighted(rng, |(_, count)| **count).ok()?;
+            if **c == END {
+                break;
+            }
+            word.push(**c);
+            context.push(**c);
+            context.remove(0);
+        }
+        Some(word)
+    }
+}
+
+/// 학습한 모형으로 사전에 없는 낱말을 만듭니다.
+pub(crate) fn generate(model: &MarkovModel, option: &GenerateOption) -> Vec<String> {
+    let min = option.min_syllables.unwrap_or(DEFAULT_SYLLABLES.0);
+    let max = option.max_syllables.unwrap_or(DEFAULT_SYLLABLES.1.max(min));
+    option.collect(
+        |rng| model.sample(rng, min, max).filter(|x| !model.contains(x)),
+        |x| x,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markov() {
+        let items = [
+            "고양이",
+            "고구마",
+            "구름",
+            "마을",
+            "이름",
+            "다람쥐",
+            "-었-",
+            "강",
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(code, word)| OpendictData {
+            code: code as u32,
+            ..OpendictData::test(word, "명사")
+        })
+        .collect::<Vec<_>>();
+        let model = MarkovModel::train(&items, 0, 1);
+        assert!(model.contains("고양이"));
+        assert!(!model.contains("강"));
+        assert!(!model.contains("었"));
+
+        let option = GenerateOption {
+            count: 5,
+            seed: Some(0),
+            ..Default::default()
+        };
+        let words = generate(&model, &option);
+        assert!(!words.is_empty());
+        for word in &words {
+            assert!(!model.contains(word));
+            assert!((2..=4).contains(&word.chars().count()));
+        }
+        assert_eq!(words, generate(&model, &option));
+    }
+}
@@ -1 +1,18 @@
+pub(crate) mod acrostic;
+pub(crate) mod blend;
+pub(crate) mod blocklist;
+pub(crate) mod conjugation;
+pub(crate) mod definition_index;
+pub(crate) mod explain;
+pub(crate) mod hanja;
+pub(crate) mod initials;
+pub(crate) mod length;
+pub(crate) mod markov;
 pub(crate) mod opendict;
+pub(crate) mod particle;
+pub(crate) mod rule_pack;
+pub(crate) mod score;
+pub(crate) mod similarity;
+pub(crate) mod template;
+pub(crate) mod usability;
+pub(crate) mod word_class;
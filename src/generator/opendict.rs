@@ -1,19 +1,1006 @@
-use crate::data_collector::opendict::{v1::OpendictData, Pos};
+use crate::{
+    data_collector::opendict::{v1::OpendictData, Pos, WordType},
+    generator::{
+        blocklist::Blocklist,
+        conjugation::{self, PredicateKind, Tense},
+        initials::InitialPattern,
+        length::{Length, LengthRange},
+        particle::Particle,
+        rule_pack::RulePack,
+        template::{Form, Node, Template},
+        usability::Usability,
+        word_class::WordClass,
+    },
+    hangul,
+};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-fn generate(pool: &[&OpendictData], queries: &[Pos]) -> Vec<String> {
+/// 닉네임 생성 옵션
+#[derive(Debug, Clone)]
+pub(crate) struct GenerateOption {
+    /// 생성할 닉네임 수
+    pub(crate) count: usize,
+    /// 난수 시드, 없으면 임의로 설정
+    ///
+    /// 같은 시드, 같은 사전 데이터, 같은 패턴이면 항상 같은 닉네임을 생성합니다.
+    pub(crate) seed: Option<u64>,
+    /// 닉네임 전체의 최소 한글 음절 수
+    pub(crate) min_syllables: Option<usize>,
+    /// 닉네임 전체의 최대 한글 음절 수
+    pub(crate) max_syllables: Option<usize>,
+    /// 닉네임 전체의 최소 화면 너비
+    pub(crate) min_width: Option<usize>,
+    /// 닉네임 전체의 최대 화면 너비
+    pub(crate) max_width: Option<usize>,
+    /// 슬롯마다 들어갈 단어의 최소 한글 음절 수
+    pub(crate) word_min_syllables: Option<usize>,
+    /// 슬롯마다 들어갈 단어의 최대 한글 음절 수
+    pub(crate) word_max_syllables: Option<usize>,
+    /// 두운, 모든 단어의 첫 음절 초성을 맞춥니다.
+    pub(crate) alliteration: Option<Alliteration>,
+    /// 각운, 모든 단어의 마지막 음절을 맞춥니다.
+    pub(crate) rhyme: Option<Rhyme>,
+    /// 생성한 닉네임이 따라야 하는 플랫폼 규칙
+    pub(crate) rule_pack: Option<RulePack>,
+    /// 금칙어 목록
+    pub(crate) blocklist: Blocklist,
+    /// 어미, 접사처럼 홀로 쓰이지 못하거나 정해진 꼴로만 쓰이는 단어도 사용할지
+    pub(crate) include_bound: bool,
+    /// 사용할 단어 유형, 패턴의 슬롯에 유형을 적으면 그 슬롯은 슬롯의 유형을 따릅니다.
+    pub(crate) types: Vec<WordType>,
+    /// 단어 유형별 가중치, 적지 않은 유형은 1입니다.
+    pub(crate) type_weights: HashMap<WordType, f64>,
+    /// 사용할 어종, 패턴의 슬롯에 어종을 적으면 그 슬롯은 슬롯의 어종을 따릅니다.
+    pub(crate) class: Option<WordClass>,
+    /// 주제어를 뜻풀이에 담은 단어 코드, 이 단어가 있는 슬롯은 이 단어로만 채웁니다.
+    pub(crate) theme: Option<HashSet<u32>>,
+    /// 기준 단어와 비슷한 단어 코드별 유사도, 이 단어가 있는 슬롯은 이 단어로만 채우고 비슷할수록 자주 고릅니다.
+    pub(crate) related: Option<HashMap<u32, f64>>,
+    /// 조건에 맞지 않아 제외한 닉네임과 단어를 이유와 함께 보일지, 끄면 디버그 로그로만 남깁니다.
+    pub(crate) show_dropped: bool,
+}
+
+/// 두운 조건
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Alliteration {
+    /// 아무 초성이나 모두 같게
+    Any,
+    /// 지정한 초성으로
+    Initial(char),
+}
+
+/// 각운 조건, 뒤에 있을수록 엄격합니다.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Rhyme {
+    /// 마지막 음절의 받침이 같게
+    받침,
+    /// 마지막 음절의 모음이 같게
+    모음,
+    /// 마지막 음절의 모음과 받침이 같게
+    모음받침,
+    /// 마지막 음절이 같게
+    음절,
+}
+
+impl Default for GenerateOption {
+    fn default() -> Self {
+        Self {
+            count: 10,
+            seed: None,
+            min_syllables: None,
+            max_syllables: None,
+            min_width: None,
+            max_width: None,
+            word_min_syllables: None,
+            word_max_syllables: None,
+            alliteration: None,
+            rhyme: None,
+            rule_pack: None,
+            blocklist: Blocklist::default(),
+            include_bound: false,
+            types: vec![WordType::일반어, WordType::방언, WordType::옛말],
+            type_weights: HashMap::new(),
+            class: None,
+            theme: None,
+            related: None,
+            show_dropped: false,
+        }
+    }
+}
+
+impl GenerateOption {
+    /// 조건을 만족하는 닉네임을 찾기 위해 시도할 최대 횟수
+    pub(crate) const MAX_TRY_PER_COUNT: usize = 100;
+
+    /// 단어를 고를 때의 가중치
+    /// 템플릿 슬롯처럼 단어 유형별 가중치를 따로 정한 곳에서는 그 값을 넘깁니다.
+    fn weight(&self, data: &OpendictData, type_weights: &HashMap<WordType, f64>) -> f64 {
+        let type_weight = data
+            .get_type()
+            .ok()
+            .and_then(|x| type_weights.get(&x))
+            .copied()
+            .unwrap_or(1.0);
+        let related = self
+            .related
+            .as_ref()
+            .and_then(|x| x.get(&data.code))
+            .copied()
+            .unwrap_or(1.0);
+        type_weight * related
+    }
+
+    /// 옵션의 시드로 만든 난수 생성기, 시드가 없으면 임의로 정합니다.
+    pub(crate) fn rng(&self) -> ChaCha8Rng {
+        ChaCha8Rng::seed_from_u64(self.seed.unwrap_or_else(rand::random))
+    }
+
+    /// 단어 유형, 어종, 금칙어 조건을 따르는 사전 항목인지 확인합니다.
+    /// 템플릿 슬롯처럼 단어 유형과 어종을 따로 정한 곳에서는 그 값을 넘깁니다.
+    pub(crate) fn is_allowed(
+        &self,
+        data: &OpendictData,
+        types: &[WordType],
+        class: Option<WordClass>,
+    ) -> bool {
+        if !data.get_type().is_ok_and(|x| types.contains(&x))
+            || class.is_some_and(|class| WordClass::of(data) != class)
+        {
+            return false;
+        }
+        match self.blocklist.check_word(data) {
+            Ok(()) => true,
+            Err(blocked) => {
+                self.report_dropped(&data.word, &blocked);
+                false
+            }
+        }
+    }
+
+    /// 홀로 쓸 수 있고 옵션의 단어 유형, 어종, 금칙어 조건을 따르는 사전 항목만 남깁니다.
+    pub(crate) fn filter_pool<'a>(&self, pool: &[&'a OpendictData]) -> Vec<&'a OpendictData> {
+        pool.iter()
+            .copied()
+            .filter(|x| self.include_bound || Usability::of(x) == Usability::자립)
+            .filter(|x| self.is_allowed(x, &self.types, self.class))
+            .collect()
+    }
+
+    /// 만든 닉네임이 길이, 규칙 묶음, 금칙어 조건을 따르는지 확인합니다.
+    pub(crate) fn accept(&self, text: &str) -> bool {
+        if !self.length_range().contains(Length::of(text)) {
+            return false;
+        }
+        if let Some(Err(violation)) = self.rule_pack.as_ref().map(|x| x.validate(text)) {
+            self.report_dropped(text, &violation);
+            return false;
+        }
+        if let Err(blocked) = self.blocklist.check_text(text) {
+            self.report_dropped(text, &blocked);
+            return false;
+        }
+        true
+    }
+
+    /// 제외한 닉네임이나 단어를 이유와 함께 남깁니다.
+    /// 디버그 로그는 릴리스 빌드에서 빠지므로 `show_dropped`가 켜져 있으면 정보 로그로 남깁니다.
+    fn report_dropped(&self, text: &str, reason: &dyn std::fmt::Display) {
+        if self.show_dropped {
+            tracing::info!("\"{}\" 제외: {}", text, reason);
+        } else {
+            tracing::debug!("\"{}\" 제외: {}", text, reason);
+        }
+    }
+
+    /// `count`개를 모으거나 시도 횟수를 다 쓸 때까지 `sample`로 하나씩 뽑습니다.
+    /// `text`가 이미 뽑은 것과 같거나 [`Self::accept`]를 통과하지 못하면 버립니다.
+    pub(crate) fn collect<T>(
+        &self,
+        mut sample: impl FnMut(&mut ChaCha8Rng) -> Option<T>,
+        text: impl Fn(&T) -> &str,
+    ) -> Vec<T> {
+        let mut rng = self.rng();
+        // 아주 큰 `count`로 미리 메모리를 잡거나 시도 횟수가 넘치지 않게 합니다.
+        let mut result = Vec::<T>::new();
+        for _ in 0..self.count.saturating_mul(Self::MAX_TRY_PER_COUNT) {
+            if result.len() >= self.count {
+                break;
+            }
+            let Some(x) = sample(&mut rng) else {
+                continue;
+            };
+            if result.iter().any(|y| text(y) == text(&x)) || !self.accept(text(&x)) {
+                continue;
+            }
+            result.push(x);
+        }
+        result
+    }
+
+    /// 닉네임 전체가 가질 수 있는 길이
+    pub(crate) fn length_range(&self) -> LengthRange {
+        LengthRange {
+            min: Length {
+                syllables: self.min_syllables.unwrap_or(0),
+                width: self.min_width.unwrap_or(0),
+            },
+            max: Length {
+                syllables: self.max_syllables.unwrap_or(usize::MAX),
+                width: self.max_width.unwrap_or(usize::MAX),
+            },
+        }
+    }
+
+    /// 슬롯마다 들어갈 단어가 가질 수 있는 길이
+    pub(crate) fn word_length_range(&self) -> LengthRange {
+        LengthRange {
+            min: Length {
+                syllables: self.word_min_syllables.unwrap_or(0),
+                width: 0,
+            },
+            max: Length {
+                syllables: self.word_max_syllables.unwrap_or(usize::MAX),
+                width: usize::MAX,
+            },
+        }
+    }
+}
+
+/// 생성한 닉네임
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Nickname<'a> {
+    pub(crate) text: String,
+    /// 닉네임에 쓰인 단어, 패턴 순서대로
+    pub(crate) words: Vec<Word<'a>>,
+    /// 패턴에 적은 조사와 앞 말에 맞춰 고른 형태, 패턴 순서대로
+    pub(crate) particles: Vec<(Particle, &'static str)>,
+}
+
+/// 닉네임에 쓰인 단어
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Word<'a> {
+    pub(crate) data: &'a OpendictData,
+    /// 닉네임에 들어간 형태
+    pub(crate) text: String,
+    /// 사전의 형태를 바꾼 방법
+    pub(crate) transformation: Option<Transformation>,
+}
+
+/// 단어의 형태를 바꾼 방법
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Transformation {
+    /// 용언을 관형형으로 활용
+    관형형(Tense),
+    /// 앞 말의 받침에 맞춰 조사의 형태를 고름
+    조사(Particle),
+    /// 앞 말의 끝과 같은 자모를 겹쳐 이어붙임, 겹친 자모 수
+    혼성(usize),
+    /// 한자어에 쓰인 한자 하나의 음만 땀
+    한자음(char),
+}
+
+impl std::fmt::Display for Transformation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transformation::관형형(Tense::현재) => write!(f, "관형형"),
+            Transformation::관형형(Tense::과거) => write!(f, "과거 관형형"),
+            Transformation::관형형(Tense::미래) => write!(f, "미래 관형형"),
+            Transformation::조사(particle) => write!(f, "받침에 맞춘 조사 ({})", particle),
+            Transformation::혼성(overlap) => write!(f, "앞 말과 자모 {}개를 겹침", overlap),
+            Transformation::한자음(hanja) => write!(f, "{}의 음", hanja),
+        }
+    }
+}
+
+/// 품사나 단어 유형을 알 수 없어 생성에 쓰이지 않는 사전 항목을 값별 개수와 함께 알립니다.
+/// 오픈사전에 새 분류가 생기면 [`Pos`]나 [`WordType`]에 추가해야 합니다.
+pub(crate) fn warn_unknown(pool: &[&OpendictData]) {
+    let mut unknown = BTreeMap::<(&str, &str), usize>::new();
+    for data in pool {
+        if data.get_pos().is_err() {
+            *unknown.entry(("품사", &data.pos)).or_default() += 1;
+        }
+        if data.get_type().is_err() {
+            *unknown.entry(("단어 유형", &data.r#type)).or_default() += 1;
+        }
+    }
+    for ((kind, value), count) in unknown {
+        tracing::warn!(
+            "알 수 없는 {} \"{}\"인 항목 {}개를 제외합니다.",
+            kind,
+            value,
+            count
+        );
+    }
+}
+
+/// 품사 슬롯 목록에 맞는 단어를 하나씩 골라 이어붙인 닉네임을 생성합니다.
+/// 후보가 없는 슬롯이 있으면 빈 목록을 반환합니다.
+pub(crate) fn generate<'a>(
+    pool: &[&'a OpendictData],
+    queries: &[Pos],
+    option: &GenerateOption,
+) -> Vec<Nickname<'a>> {
+    generate_with_template(pool, &Template::from_pos(queries), option)
+}
+
+/// 패턴에 맞춰 닉네임을 생성합니다.
+/// 후보가 없는 슬롯이 있으면 빈 목록을 반환합니다.
+pub(crate) fn generate_with_template<'a>(
+    pool: &[&'a OpendictData],
+    template: &Template,
+    option: &GenerateOption,
+) -> Vec<Nickname<'a>> {
     let mut data_per_pos = HashMap::new();
-    for query in queries {
-        if data_per_pos.contains_key(query) {
+    for query in template.pos() {
+        if data_per_pos.contains_key(&query) {
             continue;
         }
         let d = pool
             .par_iter()
             .cloned()
-            // .filter(|x| x.pos == query)
+            .filter(|x| x.get_pos().is_ok_and(|pos| pos == query))
+            .filter(|x| option.include_bound || is_usable(x, query))
             .collect::<Vec<_>>();
+        if d.is_empty() {
+            tracing::warn!("{:?}에 해당하는 단어가 없습니다.", query);
+            return Vec::new();
+        }
         data_per_pos.insert(query, d);
     }
-    todo!()
+
+    let mut slots = HashMap::new();
+    for (index, node) in template.nodes.iter().enumerate() {
+        let Node::Word {
+            pos,
+            form,
+            types,
+            type_weights,
+            class,
+            initials,
+        } = node
+        else {
+            continue;
+        };
+        let types = if types.is_empty() {
+            &option.types
+        } else {
+            types
+        };
+        let class = class.or(option.class);
+        let mut data = data_per_pos[pos]
+            .iter()
+            .copied()
+            .filter(|x| option.is_allowed(x, types, class))
+            .collect::<Vec<_>>();
+        if let Some(theme) = &option.theme {
+            let themed = data
+                .iter()
+                .copied()
+                .filter(|x| theme.contains(&x.code))
+                .collect::<Vec<_>>();
+            if !themed.is_empty() {
+                data = themed;
+            }
+        }
+        if let Some(related) = &option.related {
+            let related = data
+                .iter()
+                .copied()
+                .filter(|x| related.contains_key(&x.code))
+                .collect::<Vec<_>>();
+            if !related.is_empty() {
+                data = related;
+            }
+        }
+        let mut slot_weights = option.type_weights.clone();
+        slot_weights.extend(type_weights.iter().copied());
+        let slot = Slot {
+            type_weights: slot_weights,
+            ..Slot::new(
+                &data,
+                *pos,
+                *form,
+                initials.as_ref(),
+                template,
+                index,
+                option,
+            )
+        };
+        slots.insert(index, slot);
+    }
+    let mut keys = agreement_keys(slots.values());
+    keys.retain(|key| prune(template, &mut slots, key, option));
+    if keys.is_empty() {
+        tracing::warn!("모든 조건을 만족하는 단어 조합이 없습니다.");
+        return Vec::new();
+    }
+
+    option.collect(
+        |rng| {
+            let key = keys.choose(rng).unwrap();
+            sample(template, &slots, key, option, rng)
+        },
+        |x| &x.text,
+    )
+}
+
+/// 슬롯에 넣을 수 있는 단어인지 확인합니다.
+/// 조사 슬롯은 앞 말에 조사를 붙이기 위한 것이므로 조사는 제외하지 않습니다.
+fn is_usable(data: &OpendictData, query: Pos) -> bool {
+    match Usability::of(data) {
+        Usability::자립 => true,
+        Usability::의존 => query == Pos::조사 && !data.word.contains('-'),
+        Usability::고정형 => false,
+    }
+}
+
+/// 닉네임을 하나 뽑습니다. 조건을 만족하지 못하면 `None`을 반환합니다.
+fn sample<'a>(
+    template: &Template,
+    slots: &HashMap<usize, Slot<'a>>,
+    key: &AgreementKey,
+    option: &GenerateOption,
+    rng: &mut ChaCha8Rng,
+) -> Option<Nickname<'a>> {
+    let allowed = option.length_range();
+    let mut nickname = String::new();
+    let mut used = Vec::new();
+    let mut particles = Vec::new();
+    for (index, node) in template.nodes.iter().enumerate() {
+        match node {
+            Node::Literal(literal) => nickname.push_str(literal),
+            Node::Word { pos, .. } => {
+                // 뒤에 올 노드의 길이를 고려해 들어갈 수 있는 음절 수의 단어 중에서 고릅니다.
+                let length = Length::of(&nickname);
+                let rest = template.nodes[index + 1..]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, node)| node_range(node, slots.get(&(index + 1 + i)), key))
+                    .fold(LengthRange::default(), LengthRange::add);
+                let min = allowed
+                    .min
+                    .syllables
+                    .saturating_sub(length.syllables.saturating_add(rest.max.syllables));
+                let max = allowed
+                    .max
+                    .syllables
+                    .checked_sub(length.syllables.saturating_add(rest.min.syllables))?;
+                let slot = &slots[&index];
+                let candidates = &slot.group(key).candidates;
+                let start = candidates.partition_point(|x| x.length.syllables < min);
+                let end = candidates.partition_point(|x| x.length.syllables <= max);
+                let candidates = candidates.get(start..end)?;
+                let candidate = if slot.type_weights.is_empty() && option.related.is_none() {
+                    candidates.choose(rng)?
+                } else {
+                    candidates
+                        .choose_weighted(rng, |x| option.weight(x.data, &slot.type_weights))
+                        .ok()?
+                };
+                // 사전의 조사는 앞 말의 받침에 맞춰 형태를 고릅니다.
+                let particle = (*pos == Pos::조사)
+                    .then(|| Particle::find(&candidate.data.word))
+                    .flatten();
+                let word = match particle {
+                    Some(particle) => Word {
+                        data: candidate.data,
+                        text: particle.attach_to(&nickname).to_owned(),
+                        transformation: Some(Transformation::조사(particle)),
+                    },
+                    None => Word {
+                        data: candidate.data,
+                        text: candidate.text.clone(),
+                        transformation: candidate.transformation,
+                    },
+                };
+                nickname.push_str(&word.text);
+                used.push(word);
+            }
+            Node::Particle(particle) => {
+                let form = particle.attach_to(&nickname);
+                nickname.push_str(form);
+                particles.push((*particle, form));
+            }
+            Node::Number(digits) => {
+                for _ in 0..*digits {
+                    nickname.push(char::from(b'0' + rng.gen_range(0..10)));
+                }
+            }
+        }
+    }
+    // 같은 단어를 두 번 쓴 닉네임은 버립니다.
+    let mut codes = used.iter().map(|x| x.data.code).collect::<Vec<_>>();
+    codes.sort();
+    let is_repeated = codes.windows(2).any(|x| x[0] == x[1]);
+    if is_repeated {
+        return None;
+    }
+    Some(Nickname {
+        text: nickname,
+        words: used,
+        particles,
+    })
+}
+
+/// 노드가 가질 수 있는 길이의 범위
+fn node_range(node: &Node, slot: Option<&Slot>, key: &AgreementKey) -> LengthRange {
+    match node {
+        Node::Literal(literal) => LengthRange::exact(Length::of(literal)),
+        Node::Word { .. } => slot.unwrap().group(key).range,
+        Node::Particle(particle) => LengthRange::exact(Length::of(particle.with_final))
+            .union(LengthRange::exact(Length::of(particle.without_final))),
+        Node::Number(digits) => LengthRange::exact(Length {
+            syllables: 0,
+            width: *digits,
+        }),
+    }
+}
+
+/// 전체 길이 조건을 만족할 수 없는 후보 단어를 미리 제거합니다.
+/// 후보가 없는 슬롯이 생기면 `false`를 반환합니다.
+fn prune(
+    template: &Template,
+    slots: &mut HashMap<usize, Slot>,
+    key: &AgreementKey,
+    option: &GenerateOption,
+) -> bool {
+    let allowed = option.length_range();
+    loop {
+        let ranges = template
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| node_range(node, slots.get(&index), key))
+            .collect::<Vec<_>>();
+        let mut changed = false;
+        for (index, slot) in slots.iter_mut() {
+            // 조사 슬롯은 모든 조건 값이 함께 쓰므로 여기서 제거하지 않습니다.
+            if !slot.agree {
+                continue;
+            }
+            let others = ranges
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i != index)
+                .fold(LengthRange::default(), |a, (_, b)| a.add(*b));
+            let group = slot.candidates.get_mut(key).unwrap();
+            let before = group.candidates.len();
+            group
+                .candidates
+                .retain(|x| allowed.overlaps(&others.add(LengthRange::exact(x.length))));
+            if group.candidates.is_empty() {
+                return false;
+            }
+            if group.candidates.len() != before {
+                group.update_range();
+                changed = true;
+            }
+        }
+        if !changed {
+            return true;
+        }
+    }
+}
+
+/// 슬롯에 들어갈 수 있는 단어
+#[derive(Debug, Clone)]
+struct Candidate<'a> {
+    data: &'a OpendictData,
+    /// 활용을 적용한 형태
+    text: String,
+    /// 적용한 활용
+    transformation: Option<Transformation>,
+    length: Length,
+}
+
+/// 모든 슬롯의 단어가 맞춰야 하는 값
+/// 조건이 없는 항목은 `None`입니다.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct AgreementKey {
+    /// 첫 음절의 초성
+    initial: Option<char>,
+    /// 마지막 음절의 초성
+    last_initial: Option<char>,
+    /// 마지막 음절의 모음
+    last_medial: Option<char>,
+    /// 마지막 음절의 받침, 받침이 없으면 `Some(None)`
+    last_final: Option<Option<char>>,
+}
+
+impl AgreementKey {
+    /// 단어의 값을 구합니다. 조건을 맞출 수 없는 단어이면 `None`을 반환합니다.
+    fn new(text: &str, option: &GenerateOption) -> Option<Self> {
+        let mut key = Self::default();
+        if let Some(alliteration) = &option.alliteration {
+            let initial = text
+                .chars()
+                .find(|c| hangul::is_syllable(*c))
+                .and_then(hangul::initial_of)?;
+            if matches!(alliteration, Alliteration::Initial(x) if *x != initial) {
+                return None;
+            }
+            key.initial = Some(initial);
+        }
+        if let Some(rhyme) = option.rhyme {
+            let last = text.chars().rev().find_map(hangul::decompose)?;
+            if rhyme == Rhyme::음절 {
+                key.last_initial = Some(last.initial);
+            }
+            if rhyme >= Rhyme::모음 {
+                key.last_medial = Some(last.medial);
+            }
+            if rhyme != Rhyme::모음 {
+                key.last_final = Some(last.final_);
+            }
+        }
+        Some(key)
+    }
+}
+
+/// 같은 조건 값을 가진 후보 단어, 음절 수 순으로 정렬되어 있습니다.
+#[derive(Debug)]
+struct Group<'a> {
+    candidates: Vec<Candidate<'a>>,
+    range: LengthRange,
+}
+
+impl Group<'_> {
+    fn update_range(&mut self) {
+        self.range =
+            LengthRange::of_all(self.candidates.iter().map(|x| x.length)).unwrap_or_default();
+    }
+}
+
+/// 패턴의 단어 슬롯
+#[derive(Debug)]
+struct Slot<'a> {
+    /// 두운, 각운 등 슬롯 사이의 조건을 따르는지, 조사는 따르지 않습니다.
+    agree: bool,
+    /// 조건 값별 후보 단어
+    candidates: HashMap<AgreementKey, Group<'a>>,
+    /// 단어 유형별 가중치, 슬롯에 적은 가중치가 생성 옵션의 가중치보다 우선합니다.
+    type_weights: HashMap<WordType, f64>,
+}
+
+impl<'a> Slot<'a> {
+    fn new(
+        data: &[&'a OpendictData],
+        pos: Pos,
+        form: Option<Form>,
+        initials: Option<&InitialPattern>,
+        template: &Template,
+        index: usize,
+        option: &GenerateOption,
+    ) -> Self {
+        let tense = match form {
+            None if template.is_followed_by_nominal(index) => Some(Tense::현재),
+            None | Some(Form::기본형) => None,
+            Some(Form::관형형) => Some(Tense::현재),
+            Some(Form::과거관형형) => Some(Tense::과거),
+            Some(Form::미래관형형) => Some(Tense::미래),
+        };
+        let kind = PredicateKind::from_pos(pos);
+        let agree = pos != Pos::조사;
+        let word_range = option.word_length_range();
+
+        let mut candidates = HashMap::<_, Vec<_>>::new();
+        for data in data {
+            let word = normalize(&data.word);
+            let conjugated = tense.zip(kind).and_then(|(tense, kind)| {
+                let text = conjugation::to_modifier(&word, kind, tense)?;
+                Some((text, Transformation::관형형(tense)))
+            });
+            let (text, transformation) = match conjugated {
+                Some((text, transformation)) => (text, Some(transformation)),
+                None => (word, None),
+            };
+            let length = Length::of(&text);
+            if !word_range.contains(length) || initials.is_some_and(|x| !x.is_match(&text)) {
+                continue;
+            }
+            // 플랫폼에서 쓸 수 없는 글자가 있는 단어는 미리 제외합니다.
+            let rule_pack = option.rule_pack.as_ref();
+            if rule_pack.is_some_and(|x| x.find_invalid_char(&text).is_some()) {
+                continue;
+            }
+            let key = if agree {
+                AgreementKey::new(&text, option)
+            } else {
+                Some(AgreementKey::default())
+            };
+            if let Some(key) = key {
+                candidates.entry(key).or_default().push(Candidate {
+                    data,
+                    text,
+                    transformation,
+                    length,
+                });
+            }
+        }
+        let candidates = candidates
+            .into_iter()
+            .map(|(key, mut candidates)| {
+                candidates.sort_by_key(|x| x.length.syllables);
+                let mut group = Group {
+                    candidates,
+                    range: LengthRange::default(),
+                };
+                group.update_range();
+                (key, group)
+            })
+            .collect();
+        Self {
+            agree,
+            candidates,
+            type_weights: HashMap::new(),
+        }
+    }
+
+    fn group(&self, key: &AgreementKey) -> &Group<'a> {
+        if self.agree {
+            &self.candidates[key]
+        } else {
+            &self.candidates[&AgreementKey::default()]
+        }
+    }
+}
+
+/// 모든 슬롯에서 후보 단어가 있는 조건 값 목록을 구합니다.
+fn agreement_keys<'a, 'b: 'a>(slots: impl Iterator<Item = &'a Slot<'b>>) -> Vec<AgreementKey> {
+    let mut keys: Option<Vec<AgreementKey>> = None;
+    for slot in slots {
+        if slot.candidates.is_empty() {
+            return Vec::new();
+        }
+        if !slot.agree {
+            continue;
+        }
+        let slot_keys = slot.candidates.keys().copied();
+        keys = Some(match keys {
+            None => slot_keys.collect(),
+            Some(keys) => slot_keys.filter(|key| keys.contains(key)).collect(),
+        });
+    }
+    // 조건을 따르는 슬롯이 없으면 조건이 없는 것과 같습니다.
+    let mut keys = keys.unwrap_or_else(|| vec![AgreementKey::default()]);
+    keys.sort();
+    keys
+}
+
+/// 사전 표제어에서 띄어쓰기 표시(^), 붙임표(-)와 공백을 뺀 형태 (고양이^자리 → 고양이자리)
+pub(crate) fn normalize(word: &str) -> String {
+    word.chars()
+        .filter(|c| !matches!(c, '^' | '-') && !c.is_whitespace())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(code: u32, word: &str, pos: &str) -> OpendictData {
+        OpendictData {
+            code,
+            ..OpendictData::test(word, pos)
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let items = [
+            data(0, "예쁘다", "형용사"),
+            data(1, "고양이", "명사"),
+            data(2, "달리다", "동사"),
+        ];
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 3,
+            ..Default::default()
+        };
+        let nicknames = generate(&pool, &[Pos::형용사, Pos::명사], &option);
+        let texts = nicknames
+            .iter()
+            .map(|x| x.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["예쁜고양이"]);
+        assert!(generate(&pool, &[Pos::부사, Pos::명사], &option).is_empty());
+
+        // 활용형을 적지 않으면 명사 앞의 용언을 관형형으로 바꾸고, 기본형을 적으면 그대로 둡니다.
+        let template = "{형용사:기본형}{명사}".parse().unwrap();
+        let nicknames = generate_with_template(&pool, &template, &option);
+        assert_eq!(nicknames[0].text, "예쁘다고양이");
+    }
+
+    #[test]
+    fn test_normalize() {
+        let items = [data(0, "공부-하다", "동사"), data(1, "고양이^자리", "명사")];
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 1,
+            ..Default::default()
+        };
+        let nicknames = generate(&pool, &[Pos::동사, Pos::명사], &option);
+        assert_eq!(nicknames[0].text, "공부하는고양이자리");
+    }
+
+    fn items() -> Vec<OpendictData> {
+        [
+            ("예쁘다", "형용사"),
+            ("귀엽다", "형용사"),
+            ("달콤하다", "형용사"),
+            ("고양이", "명사"),
+            ("달팽이", "명사"),
+            ("하늘", "명사"),
+            ("바다", "명사"),
+            ("강", "명사"),
+            ("다람쥐", "명사"),
+            ("가", "조사"),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(code, (word, pos))| data(code as u32, word, pos))
+        .collect()
+    }
+
+    #[test]
+    fn test_seed() {
+        let items = items();
+        let pool = items.iter().collect::<Vec<_>>();
+        let template = "{형용사} {명사}{조사}".parse().unwrap();
+        let option = |seed| GenerateOption {
+            count: 10,
+            seed: Some(seed),
+            ..Default::default()
+        };
+        let a = generate_with_template(&pool, &template, &option(42));
+        let b = generate_with_template(&pool, &template, &option(42));
+        let c = generate_with_template(&pool, &template, &option(43));
+        assert_eq!(a.len(), 10);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_alliteration() {
+        let items = items();
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            alliteration: Some(Alliteration::Initial('ㄷ')),
+            ..Default::default()
+        };
+        let nicknames = generate(&pool, &[Pos::형용사, Pos::명사], &option);
+        assert!(!nicknames.is_empty());
+        for Nickname { text: nickname, .. } in nicknames {
+            assert!(nickname.starts_with("달콤한"), "{}", nickname);
+            assert!(nickname.ends_with("달팽이") || nickname.ends_with("다람쥐"));
+        }
+
+        // 초성을 정하지 않으면 모든 단어의 첫 초성이 같기만 하면 됩니다.
+        let option = GenerateOption {
+            alliteration: Some(Alliteration::Any),
+            ..option
+        };
+        let nicknames = generate(&pool, &[Pos::형용사, Pos::명사], &option);
+        assert!(!nicknames.is_empty());
+        for Nickname { text: nickname, .. } in nicknames {
+            let (adjective, noun) = ["예쁜", "귀여운", "달콤한"]
+                .into_iter()
+                .find_map(|x| nickname.strip_prefix(x).map(|noun| (x, noun)))
+                .unwrap();
+            let initial = |s: &str| s.chars().next().and_then(hangul::initial_of);
+            assert_eq!(initial(adjective), initial(noun), "{}", nickname);
+        }
+    }
+
+    #[test]
+    fn test_rhyme() {
+        let items = items();
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            rhyme: Some(Rhyme::음절),
+            ..Default::default()
+        };
+        let nicknames = generate(&pool, &[Pos::명사, Pos::명사], &option);
+        assert!(!nicknames.is_empty());
+        for Nickname { text: nickname, .. } in nicknames {
+            assert!(["고양이달팽이", "달팽이고양이"].contains(&nickname.as_str()));
+        }
+
+        // 고양이, 달팽이는 ㅣ, 바다, 강은 ㅏ로 끝납니다.
+        let option = GenerateOption {
+            rhyme: Some(Rhyme::모음),
+            ..option
+        };
+        let nicknames = generate(&pool, &[Pos::명사, Pos::명사], &option);
+        assert!(!nicknames.is_empty());
+        for Nickname { text: nickname, .. } in nicknames {
+            let first = ["고양이", "달팽이", "바다", "강"]
+                .into_iter()
+                .find(|x| nickname.starts_with(x))
+                .unwrap();
+            let medial = |s: &str| {
+                s.chars()
+                    .last()
+                    .and_then(hangul::decompose)
+                    .map(|x| x.medial)
+            };
+            assert_eq!(medial(first), medial(&nickname), "{}", nickname);
+        }
+    }
+
+    #[test]
+    fn test_length() {
+        let items = items();
+        let pool = items.iter().collect::<Vec<_>>();
+        let template = "{형용사} {명사}{숫자:2}".parse().unwrap();
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            max_syllables: Some(4),
+            max_width: Some(11),
+            word_min_syllables: Some(2),
+            ..Default::default()
+        };
+        let nicknames = generate_with_template(&pool, &template, &option);
+        assert!(!nicknames.is_empty());
+        for Nickname { text: nickname, .. } in nicknames {
+            let length = Length::of(&nickname);
+            assert!(length.syllables <= 4 && length.width <= 11, "{}", nickname);
+            assert!(nickname.starts_with("예쁜") && !nickname.contains('강'));
+        }
+    }
+
+    #[test]
+    fn test_theme() {
+        let items = items();
+        let pool = items.iter().collect::<Vec<_>>();
+        // 형용사는 주제어에 맞는 단어가 없으므로 모든 형용사를 씁니다.
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            theme: Some(HashSet::from([5, 6])),
+            ..Default::default()
+        };
+        let nicknames = generate(&pool, &[Pos::형용사, Pos::명사], &option);
+        assert!(nicknames.len() > 2);
+        for Nickname { text: nickname, .. } in nicknames {
+            assert!(nickname.ends_with("하늘") || nickname.ends_with("바다"));
+        }
+    }
+
+    #[test]
+    fn test_type_weight() {
+        let items = [
+            data(0, "고양이", "명사"),
+            OpendictData {
+                code: 1,
+                r#type: "옛말".to_owned(),
+                ..OpendictData::test("괴", "명사")
+            },
+        ];
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = GenerateOption {
+            count: 10,
+            seed: Some(0),
+            type_weights: HashMap::from([(WordType::옛말, 0.0)]),
+            ..Default::default()
+        };
+        let texts = |template: &str| {
+            generate_with_template(&pool, &template.parse().unwrap(), &option)
+                .into_iter()
+                .map(|x| x.text)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(texts("{명사}"), vec!["고양이"]);
+        // 슬롯에 적은 가중치가 생성 옵션의 가중치보다 우선합니다.
+        assert_eq!(texts("{명사:옛말=1:일반어=0}"), vec!["괴"]);
+    }
 }
@@ -0,0 +1,166 @@
+//! 앞 말의 받침에 따라 형태가 바뀌는 조사
+
+use crate::hangul;
+
+/// 받침 유무에 따라 바뀌는 조사, (받침이 있을 때, 받침이 없을 때)
+const PARTICLES: [(&str, &str); 12] = [
+    ("은", "는"),
+    ("이", "가"),
+    ("을", "를"),
+    ("과", "와"),
+    ("으로", "로"),
+    ("으로서", "로서"),
+    ("으로써", "로써"),
+    ("이랑", "랑"),
+    ("이나", "나"),
+    ("이며", "며"),
+    ("이여", "여"),
+    ("아", "야"),
+];
+
+/// 받침과 상관없이 형태가 같은 조사
+const INVARIANT_PARTICLES: [&str; 14] = [
+    "의", "에", "에서", "에게", "한테", "께", "도", "만", "까지", "부터", "처럼", "보다", "마저",
+    "조차",
+];
+
+/// 숫자를 읽었을 때 마지막 음절의 받침 (영, 일, 이, 삼, 사, 오, 육, 칠, 팔, 구)
+const DIGIT_FINALS: [Option<char>; 10] = [
+    Some('ㅇ'),
+    Some('ㄹ'),
+    None,
+    Some('ㅁ'),
+    None,
+    None,
+    Some('ㄱ'),
+    Some('ㄹ'),
+    Some('ㄹ'),
+    None,
+];
+
+/// 받침에 따라 형태가 바뀌는 조사
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Particle {
+    /// 받침 뒤에 붙는 형태
+    pub(crate) with_final: &'static str,
+    /// 받침이 없는 말 뒤에 붙는 형태
+    pub(crate) without_final: &'static str,
+}
+
+impl Particle {
+    /// 조사의 한 형태로 조사를 찾습니다.
+    pub(crate) fn find(s: &str) -> Option<Self> {
+        PARTICLES
+            .into_iter()
+            .find(|(with_final, without_final)| s == *with_final || s == *without_final)
+            .map(Self::from)
+    }
+
+    /// 문자열 앞에 있는 `이/가`, `가/이` 형식의 조사를 찾아 조사와 나머지 문자열을 반환합니다.
+    pub(crate) fn strip_prefix(s: &str) -> Option<(Self, &str)> {
+        PARTICLES
+            .into_iter()
+            .flat_map(|(a, b)| [(a, b), (b, a)])
+            .filter_map(|(a, b)| {
+                let rest = s.strip_prefix(a)?.strip_prefix('/')?.strip_prefix(b)?;
+                Some((Self::find(a).unwrap(), rest))
+            })
+            .min_by_key(|(_, rest)| rest.len())
+    }
+
+    /// 앞 말에 맞는 형태를 반환합니다.
+    /// 앞 말이 한글이나 숫자로 끝나지 않으면 받침이 없는 것으로 봅니다.
+    pub(crate) fn attach_to(&self, preceding: &str) -> &'static str {
+        let last = preceding.chars().last();
+        let final_ = match last {
+            Some(c) if hangul::is_syllable(c) => hangul::decompose(c).unwrap().final_,
+            Some(c @ '0'..='9') => DIGIT_FINALS[c as usize - '0' as usize],
+            _ => None,
+        };
+        match final_ {
+            // '으로'는 ㄹ 받침 뒤에서 '로'가 됩니다.
+            Some('ㄹ') if self.with_final.starts_with("으로") => self.without_final,
+            Some(_) => self.with_final,
+            None => self.without_final,
+        }
+    }
+}
+
+/// 어절 끝에 붙은 조사를 떼고 앞말을 반환합니다. 조사가 없으면 `None`을 반환합니다.
+/// 앞말의 받침과 맞지 않는 형태는 조사로 보지 않습니다. (바다를 → 바다, 하늘로 → 하늘, 바다을 → 없음)
+pub(crate) fn strip_particle(token: &str) -> Option<&str> {
+    let alternating = PARTICLES
+        .into_iter()
+        .flat_map(|(a, b)| [a, b].map(|form| (form, Some(Particle::from((a, b))))));
+    let invariant = INVARIANT_PARTICLES.into_iter().map(|form| (form, None));
+    alternating
+        .chain(invariant)
+        .filter_map(|(form, particle)| {
+            let stem = token.strip_suffix(form).filter(|x| !x.is_empty())?;
+            let agrees = particle.is_none_or(|x: Particle| x.attach_to(stem) == form);
+            agrees.then_some(stem)
+        })
+        .min_by_key(|stem| stem.len())
+}
+
+impl From<(&'static str, &'static str)> for Particle {
+    fn from((with_final, without_final): (&'static str, &'static str)) -> Self {
+        Self {
+            with_final,
+            without_final,
+        }
+    }
+}
+
+impl std::fmt::Display for Particle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.with_final, self.without_final)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_particle, Particle};
+
+    #[test]
+    fn test_attach() {
+        let particle = |s| Particle::find(s).unwrap();
+        assert_eq!(particle("가").attach_to("고양이"), "가");
+        assert_eq!(particle("가").attach_to("달팽"), "이");
+        assert_eq!(particle("는").attach_to("하늘"), "은");
+        assert_eq!(particle("을").attach_to("바다"), "를");
+        assert_eq!(particle("와").attach_to("별"), "과");
+        assert_eq!(particle("으로").attach_to("하늘"), "로");
+        assert_eq!(particle("으로").attach_to("강"), "으로");
+        assert_eq!(particle("로").attach_to("바다"), "로");
+        assert_eq!(particle("이").attach_to("토끼7"), "이");
+        assert_eq!(particle("이").attach_to("토끼2"), "가");
+        assert_eq!(Particle::find("의"), None);
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        assert_eq!(
+            Particle::strip_prefix("이/가 좋아"),
+            Some((Particle::find("이").unwrap(), " 좋아"))
+        );
+        assert_eq!(
+            Particle::strip_prefix("로/으로"),
+            Some((Particle::find("으로").unwrap(), ""))
+        );
+        assert_eq!(
+            Particle::strip_prefix("으로서/로서"),
+            Some((Particle::find("로서").unwrap(), ""))
+        );
+        assert_eq!(Particle::strip_prefix("의"), None);
+    }
+
+    #[test]
+    fn test_strip_particle() {
+        assert_eq!(strip_particle("바다를"), Some("바다"));
+        assert_eq!(strip_particle("하늘로"), Some("하늘"));
+        assert_eq!(strip_particle("바다에서"), Some("바다"));
+        assert_eq!(strip_particle("바다을"), None);
+        assert_eq!(strip_particle("바다"), None);
+    }
+}
@@ -0,0 +1,222 @@
+//! 플랫폼별 닉네임 규칙
+
+use serde::{Deserialize, Serialize};
+
+/// 플랫폼 닉네임 규칙
+///
+/// JSON 파일로 작성합니다.
+/// ```json
+/// {
+///     "name": "예시 게임",
+///     "charset": ["한글", "영문", "숫자"],
+///     "symbols": "_",
+///     "min_chars": 2,
+///     "max_chars": 8,
+///     "byte_limits": [{ "encoding": "euc-kr", "max": 12 }]
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct RulePack {
+    /// 플랫폼 이름
+    pub(crate) name: String,
+    /// 사용할 수 있는 글자 종류, 비어 있으면 모든 글자를 허용합니다.
+    #[serde(default)]
+    pub(crate) charset: Vec<Charset>,
+    /// 글자 종류 외에 추가로 허용할 기호, 공백도 여기에 적어야 허용됩니다.
+    #[serde(default)]
+    pub(crate) symbols: String,
+    /// 최소 글자 수
+    pub(crate) min_chars: Option<usize>,
+    /// 최대 글자 수
+    pub(crate) max_chars: Option<usize>,
+    /// 인코딩별 바이트 수 제한
+    #[serde(default)]
+    pub(crate) byte_limits: Vec<ByteLimit>,
+}
+
+/// 글자 종류
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) enum Charset {
+    /// 완성형 한글 음절
+    한글,
+    /// 호환용 자모
+    자모,
+    /// 영문 대소문자
+    영문,
+    숫자,
+}
+
+/// 인코딩별 바이트 수 제한
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct ByteLimit {
+    pub(crate) encoding: Encoding,
+    pub(crate) min: Option<usize>,
+    pub(crate) max: Option<usize>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) enum Encoding {
+    #[serde(rename = "utf-8")]
+    Utf8,
+    /// 확장 완성형, 모든 한글 음절을 표현할 수 있습니다.
+    #[serde(rename = "cp949")]
+    Cp949,
+    /// KS X 1001 완성형, 2350자의 한글 음절만 표현할 수 있습니다.
+    #[serde(rename = "euc-kr")]
+    EucKr,
+}
+
+/// 규칙을 어긴 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Violation {
+    /// 허용되지 않은 글자
+    Char(char),
+    /// 글자 수 부족
+    TooShort(usize),
+    /// 글자 수 초과
+    TooLong(usize),
+    /// 인코딩으로 표현할 수 없는 글자가 있음
+    Unencodable(Encoding),
+    /// 바이트 수 제한을 벗어남
+    Bytes(Encoding, usize),
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Char(c) => write!(f, "허용되지 않은 글자입니다: {:?}", c),
+            Violation::TooShort(len) => write!(f, "글자 수가 부족합니다: {}", len),
+            Violation::TooLong(len) => write!(f, "글자 수가 너무 많습니다: {}", len),
+            Violation::Unencodable(encoding) => {
+                write!(f, "{:?}로 표현할 수 없는 글자가 있습니다.", encoding)
+            }
+            Violation::Bytes(encoding, bytes) => {
+                write!(f, "{:?} 바이트 수 제한을 벗어났습니다: {}", encoding, bytes)
+            }
+        }
+    }
+}
+
+impl Encoding {
+    /// 인코딩했을 때의 바이트 수, 표현할 수 없는 글자가 있으면 `None`을 반환합니다.
+    pub(crate) fn byte_len(&self, s: &str) -> Option<usize> {
+        match self {
+            Encoding::Utf8 => Some(s.len()),
+            Encoding::Cp949 | Encoding::EucKr => {
+                // encoding_rs의 EUC-KR은 CP949와 같습니다.
+                let (bytes, _, had_errors) = encoding_rs::EUC_KR.encode(s);
+                if had_errors {
+                    return None;
+                }
+                if *self == Encoding::EucKr && !is_ks_x_1001(&bytes) {
+                    return None;
+                }
+                Some(bytes.len())
+            }
+        }
+    }
+}
+
+/// CP949로 인코딩된 바이트가 KS X 1001 범위 안에 있는지 확인합니다.
+fn is_ks_x_1001(bytes: &[u8]) -> bool {
+    let mut bytes = bytes.iter();
+    while let Some(lead) = bytes.next() {
+        if lead.is_ascii() {
+            continue;
+        }
+        let trail = bytes.next();
+        let is_ks = |x: &u8| (0xA1..=0xFE).contains(x);
+        if !is_ks(lead) || !trail.is_some_and(is_ks) {
+            return false;
+        }
+    }
+    true
+}
+
+impl Charset {
+    fn contains(&self, c: char) -> bool {
+        match self {
+            Charset::한글 => crate::hangul::is_syllable(c),
+            Charset::자모 => crate::hangul::is_jamo(c),
+            Charset::영문 => c.is_ascii_alphabetic(),
+            Charset::숫자 => c.is_ascii_digit(),
+        }
+    }
+}
+
+impl RulePack {
+    pub(crate) fn load(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /// 허용되지 않은 글자를 찾습니다.
+    pub(crate) fn find_invalid_char(&self, s: &str) -> Option<char> {
+        if self.charset.is_empty() {
+            return None;
+        }
+        s.chars().find(|c| {
+            !self.symbols.contains(*c) && !self.charset.iter().any(|charset| charset.contains(*c))
+        })
+    }
+
+    /// 닉네임이 규칙을 만족하는지 확인합니다.
+    pub(crate) fn validate(&self, nickname: &str) -> Result<(), Violation> {
+        if let Some(c) = self.find_invalid_char(nickname) {
+            return Err(Violation::Char(c));
+        }
+        let len = nickname.chars().count();
+        if self.min_chars.is_some_and(|min| len < min) {
+            return Err(Violation::TooShort(len));
+        }
+        if self.max_chars.is_some_and(|max| len > max) {
+            return Err(Violation::TooLong(len));
+        }
+        for limit in &self.byte_limits {
+            let bytes = limit
+                .encoding
+                .byte_len(nickname)
+                .ok_or(Violation::Unencodable(limit.encoding))?;
+            if limit.min.is_some_and(|min| bytes < min) || limit.max.is_some_and(|max| bytes > max)
+            {
+                return Err(Violation::Bytes(limit.encoding, bytes));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let rule: RulePack = serde_json::from_str(
+            r#"{
+                "name": "test",
+                "charset": ["한글", "숫자"],
+                "min_chars": 2,
+                "byte_limits": [{ "encoding": "euc-kr", "max": 8 }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(rule.validate("고양이12"), Ok(()));
+        assert_eq!(rule.validate("고양이 12"), Err(Violation::Char(' ')));
+        assert_eq!(rule.validate("a"), Err(Violation::Char('a')));
+        assert_eq!(rule.validate("별"), Err(Violation::TooShort(1)));
+        assert_eq!(
+            rule.validate("예쁜고양이"),
+            Err(Violation::Bytes(Encoding::EucKr, 10))
+        );
+        // '똠'은 CP949에만 있습니다.
+        assert_eq!(
+            rule.validate("똠양"),
+            Err(Violation::Unencodable(Encoding::EucKr))
+        );
+        assert_eq!(Encoding::Cp949.byte_len("똠양"), Some(4));
+        assert_eq!(Encoding::Utf8.byte_len("똠양"), Some(6));
+    }
+}
@@ -0,0 +1,308 @@
+//! 닉네임 점수 매기기
+//!
+//! 점수 항목은 [`Feature`]를 구현해 추가할 수 있고, 항목별 가중치를 바꿀 수 있습니다.
+
+use crate::{
+    data_collector::opendict::WordType,
+    generator::{
+        length::Length,
+        opendict::{GenerateOption, Nickname},
+    },
+    hangul,
+};
+
+/// 길이 조건이 없을 때 가장 좋은 한글 음절 수
+const DEFAULT_TARGET_SYLLABLES: usize = 4;
+
+/// 발음하기 어려운 겹받침
+const COMPOUND_FINALS: [char; 11] = [
+    'ㄳ', 'ㄵ', 'ㄶ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅄ',
+];
+/// 된소리, 거센소리 초성
+const TENSE_INITIALS: [char; 9] = ['ㄲ', 'ㄸ', 'ㅃ', 'ㅆ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ'];
+/// 뒤에 된소리, 거센소리가 오면 발음이 막히는 받침
+const OBSTRUENT_FINALS: [char; 11] = [
+    'ㄱ', 'ㄲ', 'ㄷ', 'ㅂ', 'ㅅ', 'ㅆ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ',
+];
+
+/// 점수 항목
+pub(crate) trait Feature: Send + Sync {
+    /// 항목 이름, 가중치를 정하거나 JSON으로 출력할 때 씁니다.
+    fn name(&self) -> &'static str;
+    /// 0 이상 1 이하의 점수, 높을수록 좋습니다.
+    fn score(&self, nickname: &Nickname, option: &GenerateOption) -> f64;
+}
+
+/// 발음하기 쉬운지, 겹받침과 받침 뒤의 된소리, 거센소리가 적을수록 높습니다.
+pub(crate) struct Pronounceability;
+
+/// 길이 조건의 가운데에 가까운지, 조건이 없으면 4음절에 가까울수록 높습니다.
+pub(crate) struct LengthFit;
+
+/// 흔한 단어로 만들었는지
+///
+/// 단어별 빈도 자료가 없으므로, 일반어이고 짧은 단어일수록 흔한 것으로 봅니다.
+pub(crate) struct Commonness;
+
+/// 운율, 단어 길이가 고르고 받침이 있는 음절과 없는 음절이 번갈아 나올수록 높습니다.
+pub(crate) struct Rhythm;
+
+impl Feature for Pronounceability {
+    fn name(&self) -> &'static str {
+        "발음"
+    }
+
+    fn score(&self, nickname: &Nickname, _: &GenerateOption) -> f64 {
+        let syllables = syllables(&nickname.text);
+        if syllables.is_empty() {
+            return 1.0;
+        }
+        let compound = syllables
+            .iter()
+            .filter(|x| x.final_.is_some_and(|c| COMPOUND_FINALS.contains(&c)))
+            .count();
+        let blocked = syllables
+            .windows(2)
+            .filter(|x| {
+                x[0].final_.is_some_and(|c| OBSTRUENT_FINALS.contains(&c))
+                    && TENSE_INITIALS.contains(&x[1].initial)
+            })
+            .count();
+        (1.0 - (compound + blocked) as f64 / syllables.len() as f64).max(0.0)
+    }
+}
+
+impl Feature for LengthFit {
+    fn name(&self) -> &'static str {
+        "길이"
+    }
+
+    fn score(&self, nickname: &Nickname, option: &GenerateOption) -> f64 {
+        let target = match (option.min_syllables, option.max_syllables) {
+            (Some(min), Some(max)) => (min + max) as f64 / 2.0,
+            (Some(min), None) => min.max(DEFAULT_TARGET_SYLLABLES) as f64,
+            (None, Some(max)) => max.min(DEFAULT_TARGET_SYLLABLES) as f64,
+            (None, None) => DEFAULT_TARGET_SYLLABLES as f64,
+        };
+        let syllables = Length::of(&nickname.text).syllables as f64;
+        1.0 / (1.0 + (syllables - target).abs())
+    }
+}
+
+impl Feature for Commonness {
+    fn name(&self) -> &'static str {
+        "흔함"
+    }
+
+    fn score(&self, nickname: &Nickname, _: &GenerateOption) -> f64 {
+        // 사전에 없는 낱말은 흔하지 않습니다.
+        if nickname.words.is_empty() {
+            return 0.0;
+        }
+        let total = nickname
+            .words
+            .iter()
+            .map(|x| {
+                let r#type = match x.data.get_type() {
+                    Ok(WordType::일반어) => 1.0,
+                    _ => 0.3,
+                };
+                let long = Length::of(&x.data.word).syllables.saturating_sub(3) as f64;
+                r#type / (1.0 + long * 0.5)
+            })
+            .sum::<f64>();
+        total / nickname.words.len() as f64
+    }
+}
+
+impl Feature for Rhythm {
+    fn name(&self) -> &'static str {
+        "리듬"
+    }
+
+    fn score(&self, nickname: &Nickname, _: &GenerateOption) -> f64 {
+        let lengths = nickname
+            .words
+            .iter()
+            .map(|x| Length::of(&x.data.word).syllables)
+            .filter(|x| *x > 0);
+        let balance = match (lengths.clone().min(), lengths.max()) {
+            (Some(min), Some(max)) => min as f64 / max as f64,
+            _ => 1.0,
+        };
+        let syllables = syllables(&nickname.text);
+        let alternation = if syllables.len() < 2 {
+            1.0
+        } else {
+            let alternating = syllables
+                .windows(2)
+                .filter(|x| x[0].has_final() != x[1].has_final())
+                .count();
+            alternating as f64 / (syllables.len() - 1) as f64
+        };
+        (balance + alternation) / 2.0
+    }
+}
+
+fn syllables(s: &str) -> Vec<hangul::Syllable> {
+    s.chars().filter_map(hangul::decompose).collect()
+}
+
+/// 점수 항목과 가중치
+pub(crate) struct Scorer {
+    features: Vec<(Box<dyn Feature>, f64)>,
+}
+
+/// 매긴 점수
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Score {
+    /// 항목별 점수의 가중 평균
+    pub(crate) total: f64,
+    /// 항목별 점수
+    pub(crate) features: Vec<(&'static str, f64)>,
+}
+
+/// 점수를 매긴 닉네임
+#[derive(Debug, Clone)]
+pub(crate) struct Ranked<'a> {
+    pub(crate) nickname: Nickname<'a>,
+    pub(crate) score: Score,
+}
+
+/// 알 수 없는 점수 항목 이름
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnknownFeature(pub(crate) String);
+
+impl std::fmt::Display for UnknownFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "알 수 없는 점수 항목입니다: \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for UnknownFeature {}
+
+impl Default for Scorer {
+    fn default() -> Self {
+        Self::new()
+            .with(Pronounceability, 1.0)
+            .with(LengthFit, 1.0)
+            .with(Commonness, 1.0)
+            .with(Rhythm, 1.0)
+    }
+}
+
+impl Scorer {
+    /// 항목이 없는 점수기
+    pub(crate) fn new() -> Self {
+        Self {
+            features: Vec::new(),
+        }
+    }
+
+    /// 항목을 추가합니다.
+    pub(crate) fn with(mut self, feature: impl Feature + 'static, weight: f64) -> Self {
+        self.features.push((Box::new(feature), weight));
+        self
+    }
+
+    /// 이름으로 항목의 가중치를 바꿉니다.
+    pub(crate) fn set_weight(&mut self, name: &str, weight: f64) -> Result<(), UnknownFeature> {
+        let (_, w) = self
+            .features
+            .iter_mut()
+            .find(|(feature, _)| feature.name() == name)
+            .ok_or_else(|| UnknownFeature(name.to_owned()))?;
+        *w = weight;
+        Ok(())
+    }
+
+    pub(crate) fn score(&self, nickname: &Nickname, option: &GenerateOption) -> Score {
+        let features = self
+            .features
+            .iter()
+            .map(|(feature, _)| (feature.name(), feature.score(nickname, option)))
+            .collect::<Vec<_>>();
+        let weights = self.features.iter().map(|(_, weight)| weight);
+        let weight_sum = weights.clone().sum::<f64>();
+        let total = if weight_sum > 0.0 {
+            weights.zip(&features).map(|(w, (_, s))| w * s).sum::<f64>() / weight_sum
+        } else {
+            0.0
+        };
+        Score { total, features }
+    }
+
+    /// 점수를 매겨 높은 순으로 정렬합니다. 점수가 같으면 생성한 순서를 유지합니다.
+    pub(crate) fn rank<'a>(
+        &self,
+        nicknames: Vec<Nickname<'a>>,
+        option: &GenerateOption,
+    ) -> Vec<Ranked<'a>> {
+        let mut ranked = nicknames
+            .into_iter()
+            .map(|nickname| Ranked {
+                score: self.score(&nickname, option),
+                nickname,
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by(|a, b| b.score.total.total_cmp(&a.score.total));
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nickname(text: &str) -> Nickname<'static> {
+        Nickname {
+            text: text.to_owned(),
+            words: Vec::new(),
+            particles: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_features() {
+        let option = GenerateOption::default();
+        let score = |feature: &dyn Feature, text| feature.score(&nickname(text), &option);
+        assert_eq!(score(&Pronounceability, "고양이"), 1.0);
+        assert!(score(&Pronounceability, "닭꼬치") < score(&Pronounceability, "고양이"));
+        assert_eq!(score(&LengthFit, "예쁜고양"), 1.0);
+        assert!(score(&LengthFit, "별") < score(&LengthFit, "하늘별"));
+        assert_eq!(score(&Rhythm, "강아지별"), 2.0 / 3.0 / 2.0 + 0.5);
+    }
+
+    #[test]
+    fn test_rank() {
+        struct Short;
+        impl Feature for Short {
+            fn name(&self) -> &'static str {
+                "짧음"
+            }
+
+            fn score(&self, nickname: &Nickname, _: &GenerateOption) -> f64 {
+                1.0 / nickname.text.chars().count() as f64
+            }
+        }
+
+        let option = GenerateOption::default();
+        let nicknames = vec![nickname("고양이"), nickname("별"), nickname("하늘")];
+        let mut scorer = Scorer::new().with(Short, 1.0).with(LengthFit, 0.0);
+        let ranked = scorer.rank(nicknames.clone(), &option);
+        assert_eq!(ranked[0].nickname.text, "별");
+        assert_eq!(
+            ranked[0].score.features,
+            vec![("짧음", 1.0), ("길이", 0.25)]
+        );
+
+        scorer.set_weight("짧음", 0.0).unwrap();
+        scorer.set_weight("길이", 1.0).unwrap();
+        let ranked = scorer.rank(nicknames, &option);
+        assert_eq!(ranked[0].nickname.text, "고양이");
+        assert_eq!(
+            scorer.set_weight("발음", 1.0),
+            Err(UnknownFeature("발음".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! 뜻풀이로 만든 단어 벡터와 비슷한 단어 찾기
+//!
+//! 표제어와 뜻풀이의 어절을 두 글자씩 끊어 TF-IDF 벡터를 만들고 코사인 유사도로 비교합니다.
+//! 어절을 두 글자씩 끊으면 "바다에서", "바다의"처럼 조사가 붙어도 "바다"가 겹칩니다.
+
+use crate::{
+    data_collector::opendict::v1::OpendictData,
+    generator::{definition_index::tokenize, opendict},
+};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// 단어별 TF-IDF 벡터
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SimilarityModel {
+    /// 학습할 때의 사전 항목 리비전, 사전이 바뀌었는지 확인할 때 씁니다.
+    pub(crate) item_revision: u64,
+    /// 단어 코드별 길이가 1인 벡터, (용어 번호, 가중치)를 용어 번호 순으로 저장합니다.
+    vectors: BTreeMap<u32, Vec<(u32, f32)>>,
+}
+
+/// 표제어와 뜻풀이의 용어
+fn terms(data: &OpendictData) -> Vec<String> {
+    let word = opendict::normalize(&data.word);
+    let mut terms = Vec::new();
+    for token in tokenize(&word).chain(tokenize(&data.definition)) {
+        let chars = token.chars().collect::<Vec<_>>();
+        if chars.len() == 1 {
+            terms.push(token);
+        } else {
+            terms.extend(chars.windows(2).map(|x| x.iter().collect::<String>()));
+        }
+    }
+    terms
+}
+
+fn dot(a: &[(u32, f32)], b: &[(u32, f32)]) -> f32 {
+    let (mut i, mut j, mut sum) = (0, 0, 0.0);
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                sum += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    sum
+}
+
+fn normalize(vector: &mut [(u32, f32)]) {
+    let norm = vector.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|(_, w)| *w /= norm);
+    }
+}
+
+impl SimilarityModel {
+    /// 사전 항목으로 벡터를 만듭니다.
+    pub(crate) fn train(items: &[OpendictData], item_revision: u64) -> Self {
+        let mut ids = HashMap::<String, u32>::new();
+        // 용어가 나온 단어 수
+        let mut frequencies = Vec::<u32>::new();
+        let mut counts = Vec::with_capacity(items.len());
+        for data in items {
+            let mut count = BTreeMap::<u32, u32>::new();
+            for term in terms(data) {
+                let next = ids.len() as u32;
+                let id = *ids.entry(term).or_insert(next);
+                *count.entry(id).or_default() += 1;
+            }
+            frequencies.resize(ids.len(), 0);
+            for id in count.keys() {
+                frequencies[*id as usize] += 1;
+            }
+            counts.push((data.code, count));
+        }
+
+        let n = items.len() as f32;
+        let vectors = counts
+            .into_par_iter()
+            .filter_map(|(code, count)| {
+                let mut vector = count
+                    .into_iter()
+                    .map(|(id, tf)| {
+                        let idf = (n / frequencies[id as usize] as f32).ln();
+                        (id, (1.0 + (tf as f32).ln()) * idf)
+                    })
+                    .filter(|(_, w)| *w > 0.0)
+                    .collect::<Vec<_>>();
+                normalize(&mut vector);
+                (!vector.is_empty()).then_some((code, vector))
+            })
+            .collect();
+        Self {
+            item_revision,
+            vectors,
+        }
+    }
+
+    /// 주어진 단어들과 가장 비슷한 단어를 유사도가 높은 순으로 `count`개까지 찾습니다.
+    /// 주어진 단어와 유사도가 0인 단어는 제외합니다.
+    pub(crate) fn nearest(&self, codes: &[u32], count: usize) -> Vec<(u32, f32)> {
+        let mut query = BTreeMap::<u32, f32>::new();
+        for vector in codes.iter().filter_map(|x| self.vectors.get(x)) {
+            for (id, w) in vector {
+                *query.entry(*id).or_default() += w;
+            }
+        }
+        let mut query = query.into_iter().collect::<Vec<_>>();
+        normalize(&mut query);
+        let mut result = self
+            .vectors
+            .par_iter()
+            .filter(|(code, _)| !codes.contains(code))
+            .map(|(code, vector)| (*code, dot(&query, vector)))
+            .filter(|(_, similarity)| *similarity > 0.0)
+            .collect::<Vec<_>>();
+        result.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        result.truncate(count);
+        result
+    }
+}
+
+/// 표제어가 `word`인 단어 코드, 띄어쓰기 표시(^)와 붙임표(-)는 무시합니다.
+pub(crate) fn find_codes(items: &[OpendictData], word: &str) -> Vec<u32> {
+    items
+        .iter()
+        .filter(|x| opendict::normalize(&x.word) == word)
+        .map(|x| x.code)
+        .collect()
+}
+
+/// 저장된 모형을 불러옵니다. 저장된 모형이 없거나 사전이 바뀌었으면 다시 만들어 저장합니다.
+pub(crate) fn load() -> SimilarityModel {
+    let item_revision = crate::prelude::get_opendict_item_revision();
+    if let Some(model) =
+        crate::prelude::get_similarity_model().filter(|x| x.item_revision == item_revision)
+    {
+        return model;
+    }
+    tracing::info!("단어 벡터를 만듭니다.");
+    let items = crate::prelude::get_opendict_items();
+    let model = SimilarityModel::train(&items, item_revision);
+    crate::prelude::set_similarity_model(&model);
+    model
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest() {
+        let items = [
+            ("바다", "지구 위에서 짠물로 덮인 넓은 곳."),
+            ("파도", "바다에 이는 물결."),
+            ("갈매기", "바다 위를 나는 새."),
+            ("별", "밤하늘에 빛나는 천체."),
+            ("달", "밤하늘에 떠서 빛나는 위성."),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(code, (word, definition))| OpendictData {
+            definition: definition.to_owned(),
+            code: code as u32,
+            ..OpendictData::test(word, "명사")
+        })
+        .collect::<Vec<_>>();
+        let model = SimilarityModel::train(&items, 0);
+        let nearest = model.nearest(&[0], 10);
+        let codes = nearest.iter().map(|x| x.0).collect::<Vec<_>>();
+        assert_eq!(codes[..2], [1, 2]);
+        assert!(!codes.contains(&0) && !codes.contains(&3));
+        assert_eq!(model.nearest(&[3], 1)[0].0, 4);
+        // 두 단어를 주면 두 단어를 뺀 나머지에서 찾습니다.
+        let nearest = model.nearest(&[1, 2], 10);
+        assert_eq!(nearest[0].0, 0);
+        assert!(nearest.iter().all(|x| x.1 > 0.0 && x.1 <= 1.0));
+    }
+}
@@ -0,0 +1,451 @@
+use crate::{
+    data_collector::opendict::{Pos, WordType},
+    generator::{initials::InitialPattern, particle::Particle, word_class::WordClass},
+    hangul,
+};
+
+/// 닉네임 패턴
+///
+/// `{형용사:관형형}{명사}`, `{명사}{숫자:2}`, `{명사}의{명사}`처럼
+/// 중괄호 안에 슬롯을, 중괄호 밖에 그대로 출력할 글자를 적습니다.
+/// 중괄호 자체는 `{{`, `}}`로 적습니다.
+///
+/// 슬롯 바로 뒤에 `이/가`처럼 적은 조사는 앞 말의 받침에 맞는 형태로 바뀝니다.
+/// `{명사:옛말}`, `{형용사:관형형:방언:옛말}`처럼 슬롯에 쓸 단어 유형을 정할 수 있습니다.
+/// `{명사:고유어}`처럼 어종도 정할 수 있습니다.
+/// `{명사:옛말=3}`처럼 슬롯에서 단어 유형별 가중치를 정하면 생성 옵션의 가중치보다 우선합니다.
+/// `{명사:ㄱㅇㅇ}`, `{명사:고ㅇ?}`처럼 초성 패턴과 맞는 단어만 쓰게 할 수 있습니다.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Template {
+    pub(crate) nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    /// 그대로 출력할 글자
+    Literal(String),
+    /// 사전에서 고를 단어
+    Word {
+        pos: Pos,
+        /// 활용형, 없으면 뒤에 명사가 오는 용언만 관형형으로 바꿉니다.
+        form: Option<Form>,
+        /// 사용할 단어 유형, 비어 있으면 생성 옵션을 따릅니다.
+        types: Vec<WordType>,
+        /// 단어 유형별 가중치, 생성 옵션의 가중치보다 우선합니다.
+        type_weights: Vec<(WordType, f64)>,
+        /// 사용할 어종, 없으면 생성 옵션을 따릅니다.
+        class: Option<WordClass>,
+        /// 단어가 맞아야 하는 초성 패턴
+        initials: Option<InitialPattern>,
+    },
+    /// 임의의 숫자, 자릿수
+    Number(usize),
+    /// 앞 말의 받침에 따라 형태가 바뀌는 조사
+    Particle(Particle),
+}
+
+/// 단어의 활용형
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Form {
+    /// 사전에 저장된 형태 그대로
+    기본형,
+    /// 명사를 꾸미는 형태 (예쁜, 달리는)
+    관형형,
+    /// 과거 관형형 (예쁘던, 달린)
+    과거관형형,
+    /// 미래 관형형 (예쁠, 달릴)
+    미래관형형,
+}
+
+/// 패턴 파싱 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    /// 오류가 발생한 위치, 0부터 시작하는 글자 단위
+    pub(crate) position: usize,
+    pub(crate) kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseErrorKind {
+    /// 닫히지 않은 `{`
+    UnclosedSlot,
+    /// 짝이 없는 `}`
+    UnmatchedClose,
+    /// 비어 있는 `{}`
+    EmptySlot,
+    /// 슬롯 안의 `{`
+    NestedSlot,
+    /// 알 수 없는 슬롯 이름
+    UnknownSlot(String),
+    /// 알 수 없는 슬롯 옵션
+    UnknownOption(String),
+    /// `유형=가중치`의 잘못된 가중치
+    InvalidWeight(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}번째 글자: ", self.position + 1)?;
+        match &self.kind {
+            ParseErrorKind::UnclosedSlot => write!(f, "슬롯이 '}}'로 닫히지 않았습니다."),
+            ParseErrorKind::UnmatchedClose => {
+                write!(f, "짝이 없는 '}}'입니다. 글자로 쓰려면 '}}}}'로 적으세요.")
+            }
+            ParseErrorKind::EmptySlot => write!(f, "빈 슬롯입니다."),
+            ParseErrorKind::NestedSlot => write!(f, "슬롯 안에 슬롯을 넣을 수 없습니다."),
+            ParseErrorKind::UnknownSlot(x) => write!(f, "알 수 없는 슬롯입니다: \"{}\"", x),
+            ParseErrorKind::UnknownOption(x) => {
+                write!(f, "알 수 없는 슬롯 옵션입니다: \"{}\"", x)
+            }
+            ParseErrorKind::InvalidWeight(x) => {
+                write!(f, "가중치가 올바르지 않습니다: \"{}\"", x)
+            }
+        }
+    }
+}
+impl std::error::Error for ParseError {}
+
+impl Template {
+    /// 품사 슬롯만으로 이루어진 패턴
+    pub(crate) fn from_pos(queries: &[Pos]) -> Self {
+        Self {
+            nodes: queries
+                .iter()
+                .map(|pos| Node::Word {
+                    pos: *pos,
+                    form: None,
+                    types: Vec::new(),
+                    type_weights: Vec::new(),
+                    class: None,
+                    initials: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// 패턴에 사용된 품사 목록
+    pub(crate) fn pos(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.nodes.iter().filter_map(|node| match node {
+            Node::Word { pos, .. } => Some(*pos),
+            _ => None,
+        })
+    }
+
+    /// 해당 위치의 노드 바로 뒤에 체언 슬롯이 오는지 확인합니다.
+    /// 사이에 공백만 있는 경우도 포함합니다.
+    pub(crate) fn is_followed_by_nominal(&self, index: usize) -> bool {
+        self.nodes[index + 1..]
+            .iter()
+            .find(|node| !matches!(node, Node::Literal(x) if x.trim().is_empty()))
+            .is_some_and(|node| matches!(node, Node::Word { pos, .. } if pos.is_nominal()))
+    }
+}
+
+impl std::str::FromStr for Template {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut nodes = Vec::new();
+        let mut literal = String::new();
+        let mut chars = s.chars().enumerate().peekable();
+        while let Some((position, c)) = chars.next() {
+            match c {
+                '{' if chars.next_if(|(_, c)| *c == '{').is_some() => literal.push('{'),
+                '}' if chars.next_if(|(_, c)| *c == '}').is_some() => literal.push('}'),
+                '}' => {
+                    return Err(ParseError {
+                        position,
+                        kind: ParseErrorKind::UnmatchedClose,
+                    })
+                }
+                '{' => {
+                    let mut body = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((position, '{')) => {
+                                return Err(ParseError {
+                                    position,
+                                    kind: ParseErrorKind::NestedSlot,
+                                })
+                            }
+                            Some((_, c)) => body.push(c),
+                            None => {
+                                return Err(ParseError {
+                                    position,
+                                    kind: ParseErrorKind::UnclosedSlot,
+                                })
+                            }
+                        }
+                    }
+                    push_literal(&mut nodes, std::mem::take(&mut literal));
+                    nodes.push(parse_slot(&body, position)?);
+                }
+                c => literal.push(c),
+            }
+        }
+        push_literal(&mut nodes, literal);
+        Ok(Self { nodes })
+    }
+}
+
+/// 글자 노드를 추가합니다.
+/// 슬롯 바로 뒤에 오는 글자가 `이/가` 형식의 조사로 시작하면 조사 노드로 분리합니다.
+fn push_literal(nodes: &mut Vec<Node>, literal: String) {
+    let after_slot = matches!(nodes.last(), Some(Node::Word { .. } | Node::Number(_)));
+    let (particle, rest) = match Particle::strip_prefix(&literal) {
+        Some((particle, rest)) if after_slot => (Some(particle), rest),
+        _ => (None, literal.as_str()),
+    };
+    nodes.extend(particle.map(Node::Particle));
+    if !rest.is_empty() {
+        nodes.push(Node::Literal(rest.to_owned()));
+    }
+}
+
+/// `{`와 `}` 사이의 내용을 파싱합니다.
+/// `position`은 슬롯을 여는 `{`의 위치입니다.
+fn parse_slot(body: &str, position: usize) -> Result<Node, ParseError> {
+    // `:`로 구분한 이름과 옵션, 각각의 앞에 있는 `{`나 `:`의 위치
+    let mut offset = position;
+    let mut parts = body.split(':').map(|part| {
+        let part_position = offset;
+        offset += part.chars().count() + 1;
+        (part.trim(), part_position)
+    });
+    let (name, _) = parts.next().unwrap();
+    if name.is_empty() {
+        return Err(ParseError {
+            position,
+            kind: ParseErrorKind::EmptySlot,
+        });
+    }
+    let unknown_option = |option: &str, position| ParseError {
+        position,
+        kind: ParseErrorKind::UnknownOption(option.to_owned()),
+    };
+
+    if name == "숫자" {
+        let digits = match parts.next() {
+            None => 1,
+            Some((option, position)) => option
+                .parse()
+                .ok()
+                .filter(|digits| *digits > 0)
+                .ok_or_else(|| unknown_option(option, position))?,
+        };
+        if let Some((option, position)) = parts.next() {
+            return Err(unknown_option(option, position));
+        }
+        return Ok(Node::Number(digits));
+    }
+
+    let pos = Pos::from_name(name).map_err(|_| ParseError {
+        position: position + 1,
+        kind: ParseErrorKind::UnknownSlot(name.to_owned()),
+    })?;
+    let mut form = None;
+    let mut types = Vec::new();
+    let mut type_weights = Vec::new();
+    let mut class = None;
+    let mut initials = None;
+    for (option, position) in parts {
+        match option {
+            "기본형" => form = Some(Form::기본형),
+            "관형형" => form = Some(Form::관형형),
+            "과거관형형" => form = Some(Form::과거관형형),
+            "미래관형형" => form = Some(Form::미래관형형),
+            option => {
+                if let Some((r#type, weight)) = option.split_once('=') {
+                    let r#type = r#type
+                        .parse::<WordType>()
+                        .map_err(|_| unknown_option(option, position))?;
+                    let weight = weight
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|x| x.is_finite() && *x >= 0.0)
+                        .ok_or_else(|| ParseError {
+                            position,
+                            kind: ParseErrorKind::InvalidWeight(weight.to_owned()),
+                        })?;
+                    type_weights.push((r#type, weight));
+                } else if let Ok(r#type) = option.parse::<WordType>() {
+                    types.push(r#type);
+                } else if let Ok(x) = option.parse::<WordClass>() {
+                    class = Some(x);
+                } else if let Some(x) = parse_initials(option) {
+                    initials = Some(x);
+                } else {
+                    return Err(unknown_option(option, position));
+                }
+            }
+        }
+    }
+    Ok(Node::Word {
+        pos,
+        form,
+        types,
+        type_weights,
+        class,
+        initials,
+    })
+}
+
+/// 초성이나 `?`가 들어간 슬롯 옵션을 초성 패턴으로 파싱합니다.
+/// 음절로만 이루어진 옵션은 잘못 적은 옵션 이름일 수 있으므로 초성 패턴으로 보지 않습니다.
+fn parse_initials(option: &str) -> Option<InitialPattern> {
+    if option.chars().all(hangul::is_syllable) {
+        return None;
+    }
+    option.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let template: Template = "{형용사:관형형} {명사}{숫자:2}".parse().unwrap();
+        assert_eq!(
+            template.nodes,
+            vec![
+                Node::Word {
+                    pos: Pos::형용사,
+                    form: Some(Form::관형형),
+                    types: vec![],
+                    type_weights: vec![],
+                    class: None,
+                    initials: None,
+                },
+                Node::Literal(" ".to_owned()),
+                Node::Word {
+                    pos: Pos::명사,
+                    form: None,
+                    types: vec![],
+                    type_weights: vec![],
+                    class: None,
+                    initials: None,
+                },
+                Node::Number(2),
+            ]
+        );
+        let template: Template = "{형용사:관형형:방언:옛말}{명사:순우리말}".parse().unwrap();
+        assert_eq!(
+            template.nodes,
+            vec![
+                Node::Word {
+                    pos: Pos::형용사,
+                    form: Some(Form::관형형),
+                    types: vec![WordType::방언, WordType::옛말],
+                    type_weights: vec![],
+                    class: None,
+                    initials: None,
+                },
+                Node::Word {
+                    pos: Pos::명사,
+                    form: None,
+                    types: vec![],
+                    type_weights: vec![],
+                    class: Some(WordClass::고유어),
+                    initials: None,
+                },
+            ]
+        );
+        let template: Template = "{형용사:기본형}{명사}".parse().unwrap();
+        assert!(matches!(
+            template.nodes[0],
+            Node::Word {
+                form: Some(Form::기본형),
+                ..
+            }
+        ));
+        assert!(template.is_followed_by_nominal(0));
+        let template: Template = "{명사:옛말=3:방언=0.5}".parse().unwrap();
+        assert!(matches!(
+            &template.nodes[0],
+            Node::Word { types, type_weights, .. }
+                if types.is_empty() && *type_weights == [(WordType::옛말, 3.0), (WordType::방언, 0.5)]
+        ));
+        let template: Template = "{명사:고ㅇ?}".parse().unwrap();
+        assert!(matches!(
+            &template.nodes[0],
+            Node::Word { initials: Some(x), .. } if x.to_string() == "고ㅇ?"
+        ));
+        let template: Template = "{{{명사}}}".parse().unwrap();
+        assert_eq!(
+            template.nodes,
+            vec![
+                Node::Literal("{".to_owned()),
+                Node::Word {
+                    pos: Pos::명사,
+                    form: None,
+                    types: vec![],
+                    type_weights: vec![],
+                    class: None,
+                    initials: None,
+                },
+                Node::Literal("}".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |s: &str| s.parse::<Template>().unwrap_err();
+        assert_eq!(error("{명사").kind, ParseErrorKind::UnclosedSlot);
+        assert_eq!(error("{명사").position, 0);
+        assert_eq!(error("명사}").position, 2);
+        assert_eq!(error("{명사}{}").kind, ParseErrorKind::EmptySlot);
+        assert_eq!(error("{명사}{}").position, 4);
+        assert_eq!(
+            error("가{고양이}"),
+            ParseError {
+                position: 2,
+                kind: ParseErrorKind::UnknownSlot("고양이".to_owned())
+            }
+        );
+        assert_eq!(
+            error("{명사:과거형}"),
+            ParseError {
+                position: 3,
+                kind: ParseErrorKind::UnknownOption("과거형".to_owned())
+            }
+        );
+        assert_eq!(error("{숫자:0}").position, 3);
+        assert_eq!(
+            error("{형용사:관형형:고어}"),
+            ParseError {
+                position: 8,
+                kind: ParseErrorKind::UnknownOption("고어".to_owned())
+            }
+        );
+        assert_eq!(
+            error("{명사:옛말=-1}").kind,
+            ParseErrorKind::InvalidWeight("-1".to_owned())
+        );
+        assert_eq!(
+            error("{명사:고어=2}").kind,
+            ParseErrorKind::UnknownOption("고어=2".to_owned())
+        );
+        assert_eq!(error("{명{사}").kind, ParseErrorKind::NestedSlot);
+    }
+
+    #[test]
+    fn test_parse_particle() {
+        let template: Template = "{명사}이/가 {명사}을/를".parse().unwrap();
+        assert_eq!(template.nodes.len(), 5);
+        assert_eq!(
+            template.nodes[1],
+            Node::Particle(Particle::find("이").unwrap())
+        );
+        assert_eq!(template.nodes[2], Node::Literal(" ".to_owned()));
+        assert_eq!(
+            template.nodes[4],
+            Node::Particle(Particle::find("을").unwrap())
+        );
+        let template: Template = "이/가{명사}의{명사}".parse().unwrap();
+        assert_eq!(template.nodes[0], Node::Literal("이/가".to_owned()));
+        assert_eq!(template.nodes[2], Node::Literal("의".to_owned()));
+    }
+}
@@ -0,0 +1,102 @@
+//! 닉네임 재료로 쓸 수 있는 단어인지 분류
+
+use crate::data_collector::opendict::{v1::OpendictData, Pos};
+
+/// 홀로 쓰이지 못하는 품사
+const BOUND_POS: [Pos; 5] = [
+    Pos::어미,
+    Pos::접사,
+    Pos::조사,
+    Pos::의존명사,
+    Pos::의존명사조사,
+];
+
+/// 다른 말 뒤나 앞에 붙어서만 쓰인다는 문법 정보
+const BOUND_ANNOTATIONS: [&str; 3] = ["뒤에 붙어", "앞에 붙어", "뒤에 쓰여"];
+
+/// 정해진 꼴로만 쓰인다는 문법 정보인지 확인합니다.
+/// ((주로 ‘도와’ 꼴로 ‘길’과 함께 쓰여))처럼 `꼴로`와 `쓰여` 사이에 다른 말이 있어도 됩니다.
+fn is_fixed_form(annotation: &str) -> bool {
+    annotation
+        .find("꼴로")
+        .is_some_and(|i| annotation[i..].contains("쓰여"))
+}
+
+/// 단어를 닉네임 재료로 쓸 수 있는지
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Usability {
+    /// 홀로 쓸 수 있음
+    자립,
+    /// 어미, 접사처럼 다른 말에 붙어서만 쓰임
+    의존,
+    /// 관용구처럼 정해진 꼴로만 쓰임 (예: ((주로 ‘정하게’ 꼴로 쓰여)))
+    고정형,
+}
+
+impl Usability {
+    pub(crate) fn of(data: &OpendictData) -> Self {
+        let annotation = data.syntactic_annotation.as_deref().unwrap_or_default();
+        // "-었-", "-스럽다"처럼 붙임표가 있는 말은 다른 말에 붙어 쓰입니다.
+        let is_bound = data.get_pos().is_ok_and(|pos| BOUND_POS.contains(&pos))
+            || data.word.starts_with('-')
+            || data.word.ends_with('-')
+            || BOUND_ANNOTATIONS.iter().any(|x| annotation.contains(x));
+        if is_bound {
+            Usability::의존
+        } else if is_fixed_form(annotation) {
+            Usability::고정형
+        } else {
+            Usability::자립
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usability() {
+        let data = |word: &str, pos: &str, annotation: Option<&str>| OpendictData {
+            syntactic_annotation: annotation.map(str::to_owned),
+            ..OpendictData::test(word, pos)
+        };
+        assert_eq!(
+            Usability::of(&data("고양이", "명사", None)),
+            Usability::자립
+        );
+        assert_eq!(Usability::of(&data("-었-", "어미", None)), Usability::의존);
+        assert_eq!(
+            Usability::of(&data("-스럽다", "접사", None)),
+            Usability::의존
+        );
+        assert_eq!(
+            Usability::of(&data("것", "의존 명사", None)),
+            Usability::의존
+        );
+        assert_eq!(
+            Usability::of(&data(
+                "ㄴ다",
+                "",
+                Some("((받침 없는 용언의 어간 뒤에 붙어))")
+            )),
+            Usability::의존
+        );
+        assert_eq!(
+            Usability::of(&data(
+                "정하다",
+                "형용사",
+                Some("((주로 ‘정하게’ 꼴로 쓰여))")
+            )),
+            Usability::고정형
+        );
+        assert_eq!(
+            Usability::of(&data(
+                "돕다",
+                "동사",
+                Some("((주로 ‘도와’ 꼴로 ‘길’과 함께 쓰여))")
+            )),
+            Usability::고정형
+        );
+    }
+}
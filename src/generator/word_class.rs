@@ -0,0 +1,113 @@
+//! 어종 분류
+//!
+//! 오픈사전의 원어(`origin`)로 고유어, 한자어, 외래어, 혼종어를 나눕니다.
+
+use crate::data_collector::opendict::v1::OpendictData;
+use std::str::FromStr;
+
+/// 어종
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum WordClass {
+    /// 원어가 없는 순우리말
+    고유어,
+    /// 원어가 모두 한자 (예: 明月)
+    한자어,
+    /// 원어가 모두 다른 나라 글자 (예: coffee)
+    외래어,
+    /// 원어에 여러 종류의 글자가 섞임 (예: pão집, 多情하다)
+    혼종어,
+}
+
+impl WordClass {
+    pub(crate) const ALL: [WordClass; 4] = [
+        WordClass::고유어,
+        WordClass::한자어,
+        WordClass::외래어,
+        WordClass::혼종어,
+    ];
+
+    pub(crate) fn of(data: &OpendictData) -> Self {
+        Self::of_origin(data.origin.as_deref().unwrap_or_default())
+    }
+
+    /// 원어 문자열로 어종을 구합니다. 공백, 기호, 숫자는 보지 않습니다.
+    pub(crate) fn of_origin(origin: &str) -> Self {
+        let mut hangul = false;
+        let mut hanja = false;
+        let mut foreign = false;
+        for c in origin.chars().filter(|c| c.is_alphabetic()) {
+            if crate::hangul::is_syllable(c) || crate::hangul::is_jamo(c) {
+                hangul = true;
+            } else if is_hanja(c) {
+                hanja = true;
+            } else {
+                foreign = true;
+            }
+        }
+        match (hangul, hanja, foreign) {
+            (_, false, false) => WordClass::고유어,
+            (false, true, false) => WordClass::한자어,
+            (false, false, true) => WordClass::외래어,
+            _ => WordClass::혼종어,
+        }
+    }
+}
+
+/// CJK 통합 한자인지 확인합니다.
+pub(crate) fn is_hanja(c: char) -> bool {
+    matches!(c,
+        '\u{4E00}'..='\u{9FFF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{2FA1F}')
+}
+
+impl std::fmt::Display for WordClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for WordClass {
+    type Err = UnknownWordClass;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "순우리말" => Ok(WordClass::고유어),
+            s => WordClass::ALL
+                .into_iter()
+                .find(|x| x.to_string() == s)
+                .ok_or_else(|| UnknownWordClass(s.to_owned())),
+        }
+    }
+}
+
+/// 알 수 없는 어종 이름
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnknownWordClass(pub(crate) String);
+
+impl std::fmt::Display for UnknownWordClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "알 수 없는 어종입니다: \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for UnknownWordClass {}
+
+#[cfg(test)]
+mod tests {
+    use super::WordClass;
+
+    #[test]
+    fn test_of_origin() {
+        assert_eq!(WordClass::of_origin(""), WordClass::고유어);
+        assert_eq!(WordClass::of_origin("明月"), WordClass::한자어);
+        assert_eq!(WordClass::of_origin("coffee"), WordClass::외래어);
+        assert_eq!(WordClass::of_origin("Gaus-Bonnet"), WordClass::외래어);
+        assert_eq!(WordClass::of_origin("pão집"), WordClass::혼종어);
+        assert_eq!(WordClass::of_origin("多情하다"), WordClass::혼종어);
+        assert_eq!(WordClass::of_origin("窓 glass"), WordClass::혼종어);
+        assert_eq!("순우리말".parse(), Ok(WordClass::고유어));
+        assert_eq!("한자어".parse(), Ok(WordClass::한자어));
+    }
+}
@@ -1,5 +1,662 @@
-pub(super) async fn main() {}
+use crate::{
+    data_collector::opendict::{v1::OpendictData, Pos, UnknownWordType, WordType},
+    generator::{
+        blocklist::Blocklist,
+        explain, hanja,
+        initials::InitialPattern,
+        markov::MarkovModel,
+        opendict::{Alliteration, GenerateOption, Nickname, Rhyme},
+        rule_pack::RulePack,
+        score::{Ranked, Scorer},
+        template::Template,
+        word_class::WordClass,
+    },
+};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::collections::{HashMap, HashSet};
 
-pub(super) async fn generate(args: Vec<String>) {
-    dbg!(args);
+/// 기준 단어와 비슷한 단어를 찾을 수
+const RELATED_COUNT: usize = 200;
+
+pub(super) fn command() -> Command {
+    Command::new("generator")
+        .about("Nickname Generator")
+        .arg(
+            Arg::new("count")
+                .short('n')
+                .long("count")
+                .help("생성할 닉네임 수")
+                .value_parser(value_parser!(usize))
+                .default_value("10"),
+        )
+        .arg(
+            Arg::new("pattern")
+                .short('p')
+                .long("pattern")
+                .help("쉼표로 구분한 품사 목록 (예: 형용사,명사)")
+                .value_parser(parse_pattern)
+                .default_value("형용사,명사"),
+        )
+        .arg(
+            Arg::new("template")
+                .short('t')
+                .long("template")
+                .help("닉네임 패턴 (예: {형용사:관형형}{명사}{숫자:2})")
+                .value_parser(|s: &str| s.parse::<Template>().map_err(|e| e.to_string()))
+                .conflicts_with("pattern"),
+        )
+        .arg(
+            Arg::new("hanja")
+                .long("hanja")
+                .help("쉼표로 구분한 뜻마다 한자를 골라 이름을 지음 (예: 밝다,하늘)")
+                .value_delimiter(',')
+                .conflicts_with_all(["pattern", "template"]),
+        )
+        .arg(
+            Arg::new("blend")
+                .long("blend")
+                .help("패턴의 두 품사에서 단어를 하나씩 골라 겹치는 부분을 합침 (예: 바다 + 닭 = 바닭)")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["template", "hanja", "markov"]),
+        )
+        .arg(
+            Arg::new("markov")
+                .long("markov")
+                .help("사전 단어로 학습한 음절 모형으로 사전에 없는 낱말을 만듦")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["pattern", "template", "hanja"]),
+        )
+        .arg(
+            Arg::new("markov-order")
+                .long("markov-order")
+                .help("다음 음절을 고를 때 볼 앞 음절 수, 바꾸면 모형을 다시 학습함")
+                .value_parser(value_parser!(u64).range(1..))
+                .default_value("2")
+                .requires("markov"),
+        )
+        .arg(
+            Arg::new("acrostic")
+                .long("acrostic")
+                .help("주어진 말의 음절마다 그 음절로 시작하는 단어를 골라 삼행시를 지음 (예: 고양이)")
+                .conflicts_with_all(["pattern", "template", "hanja", "blend", "markov"]),
+        )
+        .arg(
+            Arg::new("initials")
+                .long("initials")
+                .help("초성 패턴과 맞는 단어나 단어 조합으로 닉네임을 만듦, ?는 아무 음절 (예: ㄱㅇㅇ, 고ㅇ?)")
+                .value_parser(|s: &str| s.parse::<InitialPattern>().map_err(|e| e.to_string()))
+                .conflicts_with_all(["pattern", "template", "hanja", "blend", "markov", "acrostic"]),
+        )
+        .arg(
+            Arg::new("max-words")
+                .long("max-words")
+                .help("초성 패턴을 나눠 이어붙일 최대 단어 수")
+                .value_parser(value_parser!(u64).range(1..))
+                .default_value("2")
+                .requires("initials"),
+        )
+        .arg(
+            Arg::new("min-syllables")
+                .long("min-syllables")
+                .help("닉네임 전체의 최소 한글 음절 수")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("max-syllables")
+                .long("max-syllables")
+                .help("닉네임 전체의 최대 한글 음절 수")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("min-width")
+                .long("min-width")
+                .help("닉네임 전체의 최소 화면 너비, 한글 음절은 2칸")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("max-width")
+                .long("max-width")
+                .help("닉네임 전체의 최대 화면 너비, 한글 음절은 2칸")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("word-min-syllables")
+                .long("word-min-syllables")
+                .help("단어마다 최소 한글 음절 수")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("word-max-syllables")
+                .long("word-max-syllables")
+                .help("단어마다 최대 한글 음절 수")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("rule-pack")
+                .long("rule-pack")
+                .help("플랫폼 닉네임 규칙 파일 (JSON)")
+                .value_parser(|s: &str| RulePack::load(s).map_err(|e| e.to_string())),
+        )
+        .arg(
+            Arg::new("blocklist")
+                .long("blocklist")
+                .help("금칙어 목록 파일 (JSON)")
+                .value_parser(|s: &str| Blocklist::load(s).map_err(|e| e.to_string())),
+        )
+        .arg(
+            Arg::new("include-bound")
+                .long("include-bound")
+                .help("어미, 접사, 의존 명사나 정해진 꼴로만 쓰이는 단어도 사용")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("alliteration")
+                .long("alliteration")
+                .help("모든 단어의 첫 초성을 맞춤, 초성을 지정할 수 있음 (예: ㄷ)")
+                .num_args(0..=1)
+                .default_missing_value("")
+                .value_parser(parse_alliteration),
+        )
+        .arg(
+            Arg::new("rhyme")
+                .long("rhyme")
+                .help("모든 단어의 마지막 음절을 맞춤 (받침, 모음, 모음받침, 음절)")
+                .value_parser(parse_rhyme),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .help("난수 시드")
+                .value_parser(value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .help("출력 형식")
+                .value_parser(["text", "json"])
+                .default_value("text"),
+        )
+        .arg(
+            Arg::new("explain")
+                .long("explain")
+                .help("닉네임마다 쓰인 단어의 품사, 코드, 뜻풀이와 바뀐 형태(활용, 조사, 혼성어의 겹친 자모, 한자음)를 함께 출력")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("show-dropped")
+                .long("show-dropped")
+                .help("금칙어, 규칙 묶음 조건에 걸려 제외한 닉네임과 단어를 이유와 함께 출력")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("type")
+                .long("type")
+                .help("사용할 단어 유형, 여러 번 지정 가능 [기본값: 일반어, 방언, 옛말]")
+                .value_parser(|s: &str| s.parse::<WordType>().map_err(|e| e.to_string()))
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("type-weight")
+                .long("type-weight")
+                .help("단어 유형별 가중치 (예: 옛말=3), 여러 번 지정 가능, 패턴에서는 슬롯마다 {명사:옛말=3}처럼 정할 수 있음")
+                .value_parser(parse_type_weight)
+                .action(ArgAction::Append)
+                .conflicts_with_all(["hanja", "blend", "markov", "acrostic", "initials"]),
+        )
+        .arg(
+            Arg::new("score-weight")
+                .long("score-weight")
+                .help("점수 항목별 가중치 (발음, 길이, 흔함, 리듬), 여러 번 지정 가능 (예: 발음=2)")
+                .value_parser(parse_score_weight)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("class")
+                .long("class")
+                .help("사용할 어종 (고유어, 한자어, 외래어, 혼종어), 고유어는 순우리말로도 적을 수 있음")
+                .value_parser(|s: &str| s.parse::<WordClass>().map_err(|e| e.to_string())),
+        )
+        .arg(
+            Arg::new("origin")
+                .long("origin")
+                .help("원어에 해당 문자열이 포함된 단어만 사용"),
+        )
+        .arg(
+            Arg::new("theme")
+                .long("theme")
+                .help("뜻풀이에 주제어가 들어간 단어를 우선 사용, 여러 번 지정 가능 (예: 바다)")
+                .action(ArgAction::Append)
+                .conflicts_with_all(["hanja", "blend", "markov", "acrostic", "initials"]),
+        )
+        .arg(
+            Arg::new("like")
+                .long("like")
+                .help("뜻풀이가 기준 단어와 비슷한 단어를 우선 사용 (예: 바다)")
+                .conflicts_with_all(["hanja", "blend", "markov", "acrostic", "initials"]),
+        )
+}
+
+pub(super) async fn main(args: &ArgMatches) {
+    crate::prelude::init();
+
+    let origin = args.get_one::<String>("origin");
+    let seed = args
+        .get_one::<u64>("seed")
+        .copied()
+        .unwrap_or_else(rand::random);
+    let mut option = GenerateOption {
+        count: *args.get_one::<usize>("count").unwrap(),
+        seed: Some(seed),
+        min_syllables: args.get_one::<usize>("min-syllables").copied(),
+        max_syllables: args.get_one::<usize>("max-syllables").copied(),
+        min_width: args.get_one::<usize>("min-width").copied(),
+        max_width: args.get_one::<usize>("max-width").copied(),
+        word_min_syllables: args.get_one::<usize>("word-min-syllables").copied(),
+        word_max_syllables: args.get_one::<usize>("word-max-syllables").copied(),
+        rule_pack: args.get_one::<RulePack>("rule-pack").cloned(),
+        blocklist: args
+            .get_one::<Blocklist>("blocklist")
+            .cloned()
+            .unwrap_or_default(),
+        class: args.get_one::<WordClass>("class").copied(),
+        include_bound: args.get_flag("include-bound"),
+        types: match args.get_many::<WordType>("type") {
+            Some(types) => types.copied().collect(),
+            None => GenerateOption::default().types,
+        },
+        type_weights: args
+            .get_many::<(WordType, f64)>("type-weight")
+            .map(|x| x.copied().collect())
+            .unwrap_or_default(),
+        alliteration: args.get_one::<Alliteration>("alliteration").copied(),
+        rhyme: args.get_one::<Rhyme>("rhyme").copied(),
+        theme: args.get_many::<String>("theme").map(|themes| {
+            crate::generator::definition_index::update();
+            let theme = themes
+                .flat_map(|x| crate::generator::definition_index::search(x))
+                .collect::<HashSet<_>>();
+            if theme.is_empty() {
+                tracing::warn!("주제어가 뜻풀이에 들어간 단어가 없습니다.");
+            }
+            theme
+        }),
+        related: None,
+        show_dropped: args.get_flag("show-dropped"),
+    };
+
+    let format = args.get_one::<String>("format").unwrap();
+    let explain = args.get_flag("explain");
+    let mut scorer = Scorer::default();
+    for (name, weight) in args
+        .get_many::<(String, f64)>("score-weight")
+        .into_iter()
+        .flatten()
+    {
+        scorer.set_weight(name, *weight).unwrap();
+    }
+    if args.get_flag("markov") {
+        let order = *args.get_one::<u64>("markov-order").unwrap() as usize;
+        let model = load_markov_model(order);
+        let nicknames = crate::generator::markov::generate(&model, &option)
+            .into_iter()
+            .map(|text| Nickname {
+                text,
+                words: Vec::new(),
+                particles: Vec::new(),
+            })
+            .collect();
+        print_nicknames(seed, &scorer.rank(nicknames, &option), format, explain);
+        return;
+    }
+
+    let items = crate::prelude::get_opendict_items();
+    let pool = items
+        .iter()
+        .filter(|x| origin.is_none_or(|origin| has_origin(x, origin)))
+        .collect::<Vec<_>>();
+    crate::generator::opendict::warn_unknown(&pool);
+    if let Some(word) = args.get_one::<String>("like") {
+        let codes = crate::generator::similarity::find_codes(&items, word);
+        if codes.is_empty() {
+            eprintln!("사전에 없는 단어입니다: \"{}\"", word);
+            return;
+        }
+        let model = crate::generator::similarity::load();
+        let mut related = model
+            .nearest(&codes, RELATED_COUNT)
+            .into_iter()
+            .map(|(code, similarity)| (code, similarity as f64))
+            .collect::<HashMap<_, _>>();
+        related.extend(codes.iter().map(|code| (*code, 1.0)));
+        option.related = Some(related);
+    }
+    if let Some(pattern) = args.get_one::<InitialPattern>("initials") {
+        let max_words = *args.get_one::<u64>("max-words").unwrap() as usize;
+        let nicknames = crate::generator::initials::generate(&pool, pattern, max_words, &option);
+        print_nicknames(seed, &scorer.rank(nicknames, &option), format, explain);
+        return;
+    }
+    if let Some(input) = args.get_one::<String>("acrostic") {
+        let nicknames = crate::generator::acrostic::generate(&pool, input, &option);
+        print_nicknames(seed, &scorer.rank(nicknames, &option), format, explain);
+        return;
+    }
+    if let Some(meanings) = args.get_many::<String>("hanja") {
+        let meanings = meanings.cloned().collect::<Vec<_>>();
+        let names = crate::generator::hanja::generate(&pool, &meanings, &option);
+        print_hanja_names(seed, &scorer.rank(names, &option), format, explain);
+        return;
+    }
+    if args.get_flag("blend") {
+        let pattern = args.get_one::<Vec<Pos>>("pattern").unwrap();
+        let [front, back] = pattern[..] else {
+            eprintln!("혼성어 패턴에는 품사가 두 개 있어야 합니다.");
+            return;
+        };
+        let blends = crate::generator::blend::generate(&pool, front, back, &option);
+        print_blends(seed, &scorer.rank(blends, &option), format, explain);
+        return;
+    }
+    let nicknames = match args.get_one::<Template>("template") {
+        Some(template) => {
+            crate::generator::opendict::generate_with_template(&pool, template, &option)
+        }
+        None => {
+            let pattern = args.get_one::<Vec<Pos>>("pattern").unwrap();
+            crate::generator::opendict::generate(&pool, pattern, &option)
+        }
+    };
+
+    print_nicknames(seed, &scorer.rank(nicknames, &option), format, explain);
+}
+
+/// 저장된 마르코프 모형을 불러옵니다.
+/// 저장된 모형이 없거나, 사전이 바뀌었거나, 차수가 다르면 다시 학습해 저장합니다.
+fn load_markov_model(order: usize) -> MarkovModel {
+    let item_revision = crate::prelude::get_opendict_item_revision();
+    if let Some(model) = crate::prelude::get_markov_model()
+        .filter(|x| x.order == order && x.item_revision == item_revision)
+    {
+        return model;
+    }
+    tracing::info!("마르코프 모형을 학습합니다.");
+    let items = crate::prelude::get_opendict_items();
+    let model = MarkovModel::train(&items, item_revision, order);
+    crate::prelude::set_markov_model(&model);
+    model
+}
+
+/// 점수와 항목별 점수를 담은 JSON, `explain`이면 쓰인 단어 설명도 담습니다.
+fn nickname_json(ranked: &Ranked, explain: bool) -> serde_json::Value {
+    let features = ranked
+        .score
+        .features
+        .iter()
+        .map(|(name, score)| (name.to_string(), serde_json::json!(score)))
+        .collect::<serde_json::Map<_, _>>();
+    let mut json = serde_json::json!({
+        "nickname": ranked.nickname.text,
+        "score": ranked.score.total,
+        "features": features,
+    });
+    if explain {
+        json["explanation"] = explain::to_json(&ranked.nickname);
+    }
+    json
+}
+
+fn print_json(seed: u64, nicknames: Vec<serde_json::Value>) {
+    let json = serde_json::json!({
+        "seed": seed,
+        "nicknames": nicknames,
+    });
+    println!("{}", serde_json::to_string_pretty(&json).unwrap())
+}
+
+fn print_nicknames(seed: u64, nicknames: &[Ranked], format: &str, explain: bool) {
+    match format {
+        "json" => print_json(
+            seed,
+            nicknames
+                .iter()
+                .map(|x| nickname_json(x, explain))
+                .collect(),
+        ),
+        _ => {
+            eprintln!("seed: {}", seed);
+            for nickname in nicknames {
+                println!("{}", nickname.nickname.text);
+                if explain {
+                    for line in explain::to_lines(&nickname.nickname) {
+                        println!("  {}", line);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 혼성어와 재료 단어를 출력합니다. (바닭 (바다 + 닭))
+fn print_blends(seed: u64, blends: &[Ranked], format: &str, explain: bool) {
+    let words = |x: &Ranked| {
+        x.nickname
+            .words
+            .iter()
+            .map(|x| x.data.word.clone())
+            .collect::<Vec<_>>()
+    };
+    match format {
+        "json" => print_json(
+            seed,
+            blends
+                .iter()
+                .map(|x| {
+                    let mut json = nickname_json(x, explain);
+                    json["words"] = serde_json::json!(words(x));
+                    json
+                })
+                .collect(),
+        ),
+        _ => {
+            eprintln!("seed: {}", seed);
+            for blend in blends {
+                println!("{} ({})", blend.nickname.text, words(blend).join(" + "));
+                if explain {
+                    for line in explain::to_lines(&blend.nickname) {
+                        println!("  {}", line);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 한자 이름과 한자를 출력합니다. (명월 (明月))
+fn print_hanja_names(seed: u64, names: &[Ranked], format: &str, explain: bool) {
+    match format {
+        "json" => print_json(
+            seed,
+            names
+                .iter()
+                .map(|x| {
+                    let mut json = nickname_json(x, explain);
+                    json["hanja"] = serde_json::json!(hanja::hanja_of(&x.nickname));
+                    json
+                })
+                .collect(),
+        ),
+        _ => {
+            eprintln!("seed: {}", seed);
+            for name in names {
+                println!(
+                    "{} ({})",
+                    name.nickname.text,
+                    hanja::hanja_of(&name.nickname)
+                );
+                if explain {
+                    for line in explain::to_lines(&name.nickname) {
+                        println!("  {}", line);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn has_origin(data: &OpendictData, origin: &str) -> bool {
+    data.origin.as_ref().is_some_and(|x| x.contains(origin))
+}
+
+/// 쉼표로 구분된 품사 목록을 파싱합니다.
+fn parse_pattern(s: &str) -> Result<Vec<Pos>, String> {
+    s.split(',')
+        .map(str::trim)
+        .map(|x| Pos::from_name(x).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// `옛말=3` 형식의 단어 유형 가중치를 파싱합니다.
+fn parse_type_weight(s: &str) -> Result<(WordType, f64), String> {
+    let (r#type, weight) = parse_weight(s)?;
+    let r#type = r#type.parse().map_err(|e: UnknownWordType| e.to_string())?;
+    Ok((r#type, weight))
+}
+
+/// `발음=2` 형식의 점수 항목 가중치를 파싱합니다.
+fn parse_score_weight(s: &str) -> Result<(String, f64), String> {
+    let (name, weight) = parse_weight(s)?;
+    Scorer::default()
+        .set_weight(name, weight)
+        .map_err(|e| e.to_string())?;
+    Ok((name.to_owned(), weight))
+}
+
+/// `이름=가중치` 형식을 파싱합니다.
+fn parse_weight(s: &str) -> Result<(&str, f64), String> {
+    let (name, weight) = s
+        .split_once('=')
+        .ok_or_else(|| format!("\"이름=가중치\" 형식이 아닙니다: \"{}\"", s))?;
+    let weight = weight
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite() && *x >= 0.0)
+        .ok_or_else(|| format!("가중치가 올바르지 않습니다: \"{}\"", weight))?;
+    Ok((name.trim(), weight))
+}
+
+fn parse_alliteration(s: &str) -> Result<Alliteration, String> {
+    let mut chars = s.trim().chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => Ok(Alliteration::Any),
+        (Some(c), None) if crate::hangul::INITIALS.contains(&c) => Ok(Alliteration::Initial(c)),
+        _ => Err(format!("초성이 아닙니다: \"{}\"", s)),
+    }
+}
+
+fn parse_rhyme(s: &str) -> Result<Rhyme, String> {
+    match s.trim() {
+        "받침" => Ok(Rhyme::받침),
+        "모음" => Ok(Rhyme::모음),
+        "모음받침" => Ok(Rhyme::모음받침),
+        "음절" => Ok(Rhyme::음절),
+        _ => Err(format!("각운 조건이 아닙니다: \"{}\"", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::opendict::Word;
+
+    #[test]
+    fn test_command() {
+        let args = command()
+            .try_get_matches_from(["generator", "-n", "3", "-p", "명사", "-f", "json"])
+            .unwrap();
+        assert_eq!(args.get_one::<usize>("count"), Some(&3));
+        assert_eq!(args.get_one::<Vec<Pos>>("pattern"), Some(&vec![Pos::명사]));
+        assert_eq!(args.get_one::<String>("format").unwrap(), "json");
+        assert!(command()
+            .try_get_matches_from(["generator", "-f", "yaml"])
+            .is_err());
+        assert!(command()
+            .try_get_matches_from(["generator", "-p", "명사,없는품사"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_mode_conflicts() {
+        let matches = |args: &[&str]| {
+            command()
+                .try_get_matches_from(["generator"].iter().chain(args))
+                .is_ok()
+        };
+        assert!(matches(&["-t", "{명사}", "--theme", "바다"]));
+        assert!(!matches(&["--acrostic", "고양이", "--theme", "바다"]));
+        assert!(!matches(&["--initials", "ㄱㅇ", "--theme", "바다"]));
+        assert!(!matches(&["--blend", "--theme", "바다"]));
+        assert!(matches(&["-p", "형용사,명사", "--like", "바다"]));
+        assert!(!matches(&["--acrostic", "고양이", "--like", "바다"]));
+        assert!(!matches(&["--initials", "ㄱㅇ", "--like", "바다"]));
+        assert!(!matches(&[
+            "--acrostic",
+            "고양이",
+            "--type-weight",
+            "옛말=3"
+        ]));
+        assert!(!matches(&["--markov", "--type-weight", "옛말=3"]));
+    }
+
+    #[test]
+    fn test_type_args() {
+        let args = command()
+            .try_get_matches_from(["generator", "--type", "옛말", "--type-weight", "방언=0.5"])
+            .unwrap();
+        let types = args.get_many::<WordType>("type").unwrap();
+        assert_eq!(types.copied().collect::<Vec<_>>(), vec![WordType::옛말]);
+        assert_eq!(
+            args.get_one::<(WordType, f64)>("type-weight"),
+            Some(&(WordType::방언, 0.5))
+        );
+        let err = command()
+            .try_get_matches_from(["generator", "--type", "표준어"])
+            .unwrap_err();
+        assert!(err.to_string().contains("알 수 없는 단어 유형입니다"));
+    }
+
+    #[test]
+    fn test_rhyme() {
+        let args = command()
+            .try_get_matches_from(["generator", "--rhyme", "모음받침"])
+            .unwrap();
+        assert_eq!(args.get_one::<Rhyme>("rhyme"), Some(&Rhyme::모음받침));
+        let err = command()
+            .try_get_matches_from(["generator", "--rhyme", "자음"])
+            .unwrap_err();
+        assert!(err.to_string().contains("각운 조건이 아닙니다"));
+    }
+
+    #[test]
+    fn test_nickname_json() {
+        let data = OpendictData::test("고양이", "명사");
+        let nickname = Nickname {
+            text: "고양이".to_owned(),
+            words: vec![Word {
+                data: &data,
+                text: "고양이".to_owned(),
+                transformation: None,
+            }],
+            particles: Vec::new(),
+        };
+        let ranked = Scorer::default().rank(vec![nickname], &GenerateOption::default());
+        let json = nickname_json(&ranked[0], false);
+        assert_eq!(json["nickname"], "고양이");
+        assert_eq!(json["score"], ranked[0].score.total);
+        assert_eq!(json["features"].as_object().unwrap().len(), 4);
+        assert!(json.get("explanation").is_none());
+        let json = nickname_json(&ranked[0], true);
+        assert_eq!(json["explanation"]["words"][0]["word"], "고양이");
+    }
 }
@@ -0,0 +1,243 @@
+//! 한글 자모 분해/조합
+//!
+//! 자모는 모두 호환용 자모(ㄱ, ㅏ)로 다룹니다.
+//! 첫가끝 자모(U+1100..)는 [`to_compatibility`]로 호환용 자모로 바꿀 수 있습니다.
+
+/// 첫 한글 음절
+pub(crate) const SYLLABLE_FIRST: char = '가';
+/// 마지막 한글 음절
+pub(crate) const SYLLABLE_LAST: char = '힣';
+
+/// 초성 목록
+pub(crate) const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+/// 중성 목록
+pub(crate) const MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+/// 종성 목록, 받침 없음은 제외
+pub(crate) const FINALS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+/// 두 모음이 합쳐진 중성
+const COMPOUND_MEDIALS: [(char, char, char); 7] = [
+    ('ㅗ', 'ㅏ', 'ㅘ'),
+    ('ㅗ', 'ㅐ', 'ㅙ'),
+    ('ㅗ', 'ㅣ', 'ㅚ'),
+    ('ㅜ', 'ㅓ', 'ㅝ'),
+    ('ㅜ', 'ㅔ', 'ㅞ'),
+    ('ㅜ', 'ㅣ', 'ㅟ'),
+    ('ㅡ', 'ㅣ', 'ㅢ'),
+];
+/// 두 자음이 합쳐진 종성
+const COMPOUND_FINALS: [(char, char, char); 11] = [
+    ('ㄱ', 'ㅅ', 'ㄳ'),
+    ('ㄴ', 'ㅈ', 'ㄵ'),
+    ('ㄴ', 'ㅎ', 'ㄶ'),
+    ('ㄹ', 'ㄱ', 'ㄺ'),
+    ('ㄹ', 'ㅁ', 'ㄻ'),
+    ('ㄹ', 'ㅂ', 'ㄼ'),
+    ('ㄹ', 'ㅅ', 'ㄽ'),
+    ('ㄹ', 'ㅌ', 'ㄾ'),
+    ('ㄹ', 'ㅍ', 'ㄿ'),
+    ('ㄹ', 'ㅎ', 'ㅀ'),
+    ('ㅂ', 'ㅅ', 'ㅄ'),
+];
+
+const CHOSEONG_FIRST: u32 = 0x1100;
+const JUNGSEONG_FIRST: u32 = 0x1161;
+const JONGSEONG_FIRST: u32 = 0x11A8;
+
+/// 분해된 한글 음절
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Syllable {
+    /// 초성
+    pub(crate) initial: char,
+    /// 중성
+    pub(crate) medial: char,
+    /// 종성, 받침이 없으면 `None`
+    pub(crate) final_: Option<char>,
+}
+
+impl Syllable {
+    /// 받침이 있는지 확인합니다.
+    pub(crate) fn has_final(&self) -> bool {
+        self.final_.is_some()
+    }
+}
+
+/// 한글 음절인지 확인합니다.
+pub(crate) fn is_syllable(c: char) -> bool {
+    (SYLLABLE_FIRST..=SYLLABLE_LAST).contains(&c)
+}
+
+/// 호환용 자모인지 확인합니다.
+pub(crate) fn is_jamo(c: char) -> bool {
+    ('ㄱ'..='ㅣ').contains(&c)
+}
+
+/// 한글 음절을 초성, 중성, 종성으로 분해합니다.
+pub(crate) fn decompose(c: char) -> Option<Syllable> {
+    if !is_syllable(c) {
+        return None;
+    }
+    let index = c as u32 - SYLLABLE_FIRST as u32;
+    let final_ = (index % 28) as usize;
+    let medial = (index / 28 % 21) as usize;
+    let initial = (index / 28 / 21) as usize;
+    Some(Syllable {
+        initial: INITIALS[initial],
+        medial: MEDIALS[medial],
+        final_: final_.checked_sub(1).map(|i| FINALS[i]),
+    })
+}
+
+/// 초성, 중성, 종성으로 한글 음절을 조합합니다.
+/// 첫가끝 자모도 받으며, 조합할 수 없는 자모가 있으면 `None`을 반환합니다.
+pub(crate) fn compose(initial: char, medial: char, final_: Option<char>) -> Option<char> {
+    let initial = position(&INITIALS, to_compatibility(initial))?;
+    let medial = position(&MEDIALS, to_compatibility(medial))?;
+    let final_ = match final_ {
+        Some(final_) => position(&FINALS, to_compatibility(final_))? + 1,
+        None => 0,
+    };
+    char::from_u32(SYLLABLE_FIRST as u32 + ((initial * 21 + medial) * 28 + final_) as u32)
+}
+
+/// 초성을 반환합니다.
+pub(crate) fn initial_of(c: char) -> Option<char> {
+    decompose(c).map(|x| x.initial)
+}
+
+/// 음절의 받침을 바꿉니다.
+pub(crate) fn with_final(c: char, final_: Option<char>) -> Option<char> {
+    let syllable = decompose(c)?;
+    compose(syllable.initial, syllable.medial, final_)
+}
+
+/// 첫가끝 자모를 호환용 자모로 바꿉니다. 첫가끝 자모가 아니면 그대로 반환합니다.
+pub(crate) fn to_compatibility(c: char) -> char {
+    let code = c as u32;
+    let (table, first): (&[char], u32) = match code {
+        0x1100..=0x1112 => (&INITIALS, CHOSEONG_FIRST),
+        0x1161..=0x1175 => (&MEDIALS, JUNGSEONG_FIRST),
+        0x11A8..=0x11C2 => (&FINALS, JONGSEONG_FIRST),
+        _ => return c,
+    };
+    table[(code - first) as usize]
+}
+
+/// 문자열의 한글 음절을 호환용 자모로 풀어씁니다.
+/// 겹받침과 이중 모음은 한 글자로 남깁니다.
+pub(crate) fn decompose_str(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() * 3);
+    for c in s.chars() {
+        match decompose(c) {
+            Some(syllable) => {
+                result.push(syllable.initial);
+                result.push(syllable.medial);
+                result.extend(syllable.final_);
+            }
+            None => result.push(to_compatibility(c)),
+        }
+    }
+    result
+}
+
+/// 풀어쓴 자모를 한글 음절로 모아씁니다.
+/// 음절을 이루지 못하는 자모와 다른 글자는 그대로 남깁니다.
+pub(crate) fn compose_str(s: &str) -> String {
+    let chars = s.chars().map(to_compatibility).collect::<Vec<_>>();
+    let is_medial = |i: usize| chars.get(i).is_some_and(|c| MEDIALS.contains(c));
+    let mut result = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let initial = chars[i];
+        if !INITIALS.contains(&initial) || !is_medial(i + 1) {
+            result.push(initial);
+            i += 1;
+            continue;
+        }
+        let mut medial = chars[i + 1];
+        i += 2;
+        if let Some(compound) = chars
+            .get(i)
+            .and_then(|c| combine(&COMPOUND_MEDIALS, medial, *c))
+        {
+            medial = compound;
+            i += 1;
+        }
+        let mut final_ = None;
+        if chars.get(i).is_some_and(|c| FINALS.contains(c)) && !is_medial(i + 1) {
+            final_ = Some(chars[i]);
+            i += 1;
+            if let Some(compound) = chars
+                .get(i)
+                .and_then(|c| combine(&COMPOUND_FINALS, chars[i - 1], *c))
+                .filter(|_| !is_medial(i + 1))
+            {
+                final_ = Some(compound);
+                i += 1;
+            }
+        }
+        result.push(compose(initial, medial, final_).unwrap());
+    }
+    result
+}
+
+fn position(table: &[char], c: char) -> Option<usize> {
+    table.iter().position(|x| *x == c)
+}
+
+fn combine(table: &[(char, char, char)], a: char, b: char) -> Option<char> {
+    table
+        .iter()
+        .find(|(x, y, _)| *x == a && *y == b)
+        .map(|(_, _, c)| *c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose_and_compose() {
+        assert_eq!(
+            decompose('닭'),
+            Some(Syllable {
+                initial: 'ㄷ',
+                medial: 'ㅏ',
+                final_: Some('ㄺ'),
+            })
+        );
+        assert_eq!(decompose('가').unwrap().final_, None);
+        assert_eq!(decompose('a'), None);
+        assert_eq!(compose('ㅎ', 'ㅏ', Some('ㄴ')), Some('한'));
+        assert_eq!(
+            compose('\u{1112}', '\u{1161}', Some('\u{11AB}')),
+            Some('한')
+        );
+        assert_eq!(compose('ㅏ', 'ㅏ', None), None);
+        for c in SYLLABLE_FIRST..=SYLLABLE_LAST {
+            let x = decompose(c).unwrap();
+            assert_eq!(compose(x.initial, x.medial, x.final_), Some(c));
+        }
+        assert!(decompose('강').unwrap().has_final());
+        assert!(!decompose('가').unwrap().has_final());
+        assert_eq!(with_final('살', None), Some('사'));
+    }
+
+    #[test]
+    fn test_str() {
+        assert_eq!(decompose_str("닭고기 a"), "ㄷㅏㄺㄱㅗㄱㅣ a");
+        assert_eq!(compose_str("ㄷㅏㄺㄱㅗㄱㅣ a"), "닭고기 a");
+        assert_eq!(compose_str("ㄷㅏㄹㄱㄱㅗㄱㅣ"), "닭고기");
+        assert_eq!(compose_str("ㄷㅏㄹㄱㅗㄱㅣ"), "달고기");
+        assert_eq!(compose_str("ㄱㅗㅏㅇ"), "광");
+        assert_eq!(compose_str("ㅋㅋ가"), "ㅋㅋ가");
+    }
+}
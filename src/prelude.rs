@@ -6,6 +6,11 @@ const OPENDICT_KEY: &str = "opendict_key";
 const OPENDICT_DATA_KEY: &str = "opendict_data";
 const OPENDICT_ITEM: &str = "opendict_item";
 const OPENDICT_LAST_INSERTED: &str = "opendict_last_inserted";
+const OPENDICT_ITEM_REVISION: &str = "opendict_item_revision";
+const MARKOV_MODEL: &str = "markov_model";
+const SIMILARITY_MODEL: &str = "similarity_model";
+const DEFINITION_INDEX: &str = "definition_index";
+const DEFINITION_INDEX_META: &str = "definition_index_meta";
 
 pub(crate) fn init() {
     // 로그 설정
@@ -102,6 +107,12 @@ pub(crate) fn insert_opendict_item(data: &crate::data_collector::opendict::v1::O
     )
     .unwrap();
     tree.flush().unwrap();
+    DB.update_and_fetch(OPENDICT_ITEM_REVISION, |revision| {
+        let revision = revision.map_or(0, |x| u64::from_be_bytes(x.try_into().unwrap()));
+        Some((revision + 1).to_be_bytes().to_vec())
+    })
+    .unwrap();
+    DB.flush().unwrap();
 }
 pub(crate) fn get_opendict_item_codes() -> HashSet<u32> {
     let tree = get_opendict_item_tree();
@@ -114,3 +125,86 @@ pub(crate) fn get_opendict_item_codes() -> HashSet<u32> {
     }
     codes
 }
+pub(crate) fn get_opendict_items() -> Vec<crate::data_collector::opendict::v1::OpendictData> {
+    let tree = get_opendict_item_tree();
+    let mut items = vec![];
+    for item in tree.iter() {
+        let (_, data) = item.unwrap();
+        let data = gzip_decompress(&data);
+        items.push(serde_json::from_slice(&data).unwrap());
+    }
+    items
+}
+/// 사전 항목을 저장할 때마다 1씩 늘어나는 값, 항목 전체를 읽지 않고 사전이 바뀌었는지 확인할 때 씁니다.
+pub(crate) fn get_opendict_item_revision() -> u64 {
+    let data = DB.get(OPENDICT_ITEM_REVISION).unwrap();
+    data.map_or(0, |data| {
+        u64::from_be_bytes(data.as_ref().try_into().unwrap())
+    })
+}
+pub(crate) fn get_markov_model() -> Option<crate::generator::markov::MarkovModel> {
+    let data = DB.get(MARKOV_MODEL).unwrap();
+    data.map(|data| gzip_decompress(&data))
+        .map(|data| serde_json::from_slice(&data).unwrap())
+}
+pub(crate) fn set_markov_model(model: &crate::generator::markov::MarkovModel) {
+    DB.insert(
+        MARKOV_MODEL,
+        gzip_compress(&serde_json::to_vec(model).unwrap()),
+    )
+    .unwrap();
+    DB.flush().unwrap();
+}
+pub(crate) fn get_similarity_model() -> Option<crate::generator::similarity::SimilarityModel> {
+    let data = DB.get(SIMILARITY_MODEL).unwrap();
+    data.map(|data| gzip_decompress(&data))
+        .map(|data| serde_json::from_slice(&data).unwrap())
+}
+pub(crate) fn set_similarity_model(model: &crate::generator::similarity::SimilarityModel) {
+    DB.insert(
+        SIMILARITY_MODEL,
+        gzip_compress(&serde_json::to_vec(model).unwrap()),
+    )
+    .unwrap();
+    DB.flush().unwrap();
+}
+fn get_definition_index_tree() -> sled::Tree {
+    DB.open_tree(DEFINITION_INDEX).unwrap()
+}
+/// 뜻풀이 색인을 만들 때의 정보, 형식이 다르면 `None`을 반환합니다.
+pub(crate) fn get_definition_index_meta() -> Option<crate::generator::definition_index::IndexMeta> {
+    let data = DB.get(DEFINITION_INDEX_META).unwrap();
+    data.and_then(|data| serde_json::from_slice(&data).ok())
+}
+pub(crate) fn get_definition_index(key: &str) -> Option<Vec<u32>> {
+    let tree = get_definition_index_tree();
+    let data = tree.get(key).unwrap();
+    data.map(|data| {
+        data.chunks_exact(4)
+            .map(|x| u32::from_be_bytes(x.try_into().unwrap()))
+            .collect()
+    })
+}
+pub(crate) fn set_definition_index(
+    index: &std::collections::BTreeMap<String, Vec<u32>>,
+    meta: crate::generator::definition_index::IndexMeta,
+) {
+    // 색인을 다 쓰기 전에 멈추면 다음 실행에서 다시 만들도록 정보를 먼저 지웁니다.
+    DB.remove(DEFINITION_INDEX_META).unwrap();
+    DB.flush().unwrap();
+    let tree = get_definition_index_tree();
+    tree.clear().unwrap();
+    let mut batch = sled::Batch::default();
+    for (key, codes) in index {
+        let codes = codes
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect::<Vec<_>>();
+        batch.insert(key.as_str(), codes);
+    }
+    tree.apply_batch(batch).unwrap();
+    tree.flush().unwrap();
+    DB.insert(DEFINITION_INDEX_META, serde_json::to_vec(&meta).unwrap())
+        .unwrap();
+    DB.flush().unwrap();
+}
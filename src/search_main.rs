@@ -0,0 +1,142 @@
+use crate::generator::initials::{InitialIndex, InitialPattern};
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub(super) fn command() -> Command {
+    Command::new("search")
+        .about("Search Dictionary")
+        .arg(
+            Arg::new("definition")
+                .help("뜻풀이에 들어간 주제어 (예: 바다)")
+                .required_unless_present_any(["like", "initials"]),
+        )
+        .arg(
+            Arg::new("like")
+                .long("like")
+                .help("뜻풀이가 기준 단어와 비슷한 단어를 찾음 (예: 바다)")
+                .conflicts_with("definition"),
+        )
+        .arg(
+            Arg::new("initials")
+                .long("initials")
+                .help("초성 패턴과 맞는 단어와 단어 조합을 찾음, ?는 아무 음절 (예: ㄱㅇㅇ, 고ㅇ?)")
+                .value_parser(|s: &str| s.parse::<InitialPattern>().map_err(|e| e.to_string()))
+                .conflicts_with_all(["definition", "like"]),
+        )
+        .arg(
+            Arg::new("max-words")
+                .long("max-words")
+                .help("초성 패턴을 나눠 이어붙일 최대 단어 수")
+                .value_parser(value_parser!(u64).range(1..))
+                .default_value("2")
+                .requires("initials"),
+        )
+        .arg(
+            Arg::new("count")
+                .short('n')
+                .long("count")
+                .help("출력할 최대 단어 수")
+                .value_parser(value_parser!(usize))
+                .default_value("20"),
+        )
+}
+
+pub(super) async fn main(args: &ArgMatches) {
+    crate::prelude::init();
+
+    let count = *args.get_one::<usize>("count").unwrap();
+    if let Some(word) = args.get_one::<String>("like") {
+        search_like(word, count);
+        return;
+    }
+    if let Some(pattern) = args.get_one::<InitialPattern>("initials") {
+        let max_words = *args.get_one::<u64>("max-words").unwrap() as usize;
+        search_initials(pattern, max_words, count);
+        return;
+    }
+    let keyword = args.get_one::<String>("definition").unwrap();
+    crate::generator::definition_index::update();
+    let mut codes = crate::generator::definition_index::search(keyword)
+        .into_iter()
+        .collect::<Vec<_>>();
+    codes.sort();
+    eprintln!("{}개 찾음", codes.len());
+    for code in codes.into_iter().take(count) {
+        let Some(data) = crate::prelude::get_opendict_item(code) else {
+            continue;
+        };
+        println!(
+            "{} [{}, {}]: {}",
+            data.word, data.pos, data.code, data.definition
+        );
+    }
+}
+
+fn search_like(word: &str, count: usize) {
+    let items = crate::prelude::get_opendict_items();
+    let codes = crate::generator::similarity::find_codes(&items, word);
+    if codes.is_empty() {
+        eprintln!("사전에 없는 단어입니다: \"{}\"", word);
+        return;
+    }
+    let model = crate::generator::similarity::load();
+    let items = items
+        .iter()
+        .map(|x| (x.code, x))
+        .collect::<std::collections::HashMap<_, _>>();
+    for (code, similarity) in model.nearest(&codes, count) {
+        // 모형을 만든 뒤 지워진 항목은 건너뜁니다.
+        let Some(data) = items.get(&code) else {
+            continue;
+        };
+        println!(
+            "{} [{}, {}] {:.3}: {}",
+            data.word, data.pos, data.code, similarity, data.definition
+        );
+    }
+}
+
+/// 한 단어로 맞는 단어를 먼저, 여러 단어를 이어붙인 조합을 나중에 출력합니다.
+fn search_initials(pattern: &InitialPattern, max_words: usize, count: usize) {
+    let items = crate::prelude::get_opendict_items();
+    let pool = items.iter().collect::<Vec<_>>();
+    let index = InitialIndex::new(&pool);
+    let mut printed = 0;
+    for parts in index.combinations(pattern, max_words) {
+        if let [words] = &parts[..] {
+            for word in words.iter().take(count - printed) {
+                let data = word.data;
+                println!(
+                    "{} [{}, {}]: {}",
+                    word.text, data.pos, data.code, data.definition
+                );
+                printed += 1;
+            }
+            continue;
+        }
+        // 부분별 후보 단어의 모든 조합을 차례로 출력합니다.
+        let mut indices = vec![0; parts.len()];
+        while printed < count {
+            let words = parts
+                .iter()
+                .zip(&indices)
+                .map(|(words, i)| words[*i].text.as_str())
+                .collect::<Vec<_>>();
+            println!("{}", words.join(" + "));
+            printed += 1;
+            let Some(last) = (0..parts.len())
+                .rev()
+                .find(|i| indices[*i] + 1 < parts[*i].len())
+            else {
+                break;
+            };
+            indices[last] += 1;
+            indices[last + 1..].fill(0);
+        }
+        if printed >= count {
+            break;
+        }
+    }
+    if printed == 0 {
+        eprintln!("\"{}\"에 맞는 단어가 없습니다.", pattern);
+    }
+}
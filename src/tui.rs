@@ -1,3 +1,4 @@
+mod generator;
 mod opendict_inspect;
 mod opendict_query;
 use ratatui::{
@@ -15,6 +16,7 @@ struct TuiContext<'a> {
     opendict_item_codes: Vec<u32>,
     opendict_inspect: opendict_inspect::Data<'a>,
     opendict_query: opendict_query::Data<'a>,
+    generator: generator::Data<'a>,
 }
 
 pub(super) fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -45,7 +47,7 @@ pub(super) fn main() -> Result<(), Box<dyn std::error::Error>> {
                 height: area.height - 3,
             };
             match ctx.tab_selected {
-                0 => {}
+                0 => generator::draw(frame, area, ctx),
                 1 => opendict_inspect::draw(frame, area, ctx),
                 2 => opendict_query::draw(frame, area, ctx),
                 3 => {}
@@ -66,7 +68,7 @@ pub(super) fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
             match ctx.tab_selected {
-                0 => {}
+                0 => generator::pressed_event(ctx, key.code),
                 1 => opendict_inspect::pressed_event(ctx, key.code),
                 2 => opendict_query::pressed_event(ctx, key.code),
                 3 => {}
@@ -104,6 +106,7 @@ impl<'a> Default for TuiContext<'a> {
             opendict_item_codes,
             opendict_inspect,
             opendict_query,
+            generator: generator::Data::new(),
         }
     }
 }
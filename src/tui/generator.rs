@@ -0,0 +1,112 @@
+use crate::tui::TuiContext;
+use ratatui::{crossterm::event::KeyCode, prelude::*, widgets, Frame};
+
+/// 한 번에 생성할 닉네임 수
+const COUNT: usize = 20;
+
+#[derive(Debug)]
+pub(super) struct Data<'a> {
+    /// 사전 항목, 처음 생성할 때 불러옵니다.
+    items: Option<Vec<crate::data_collector::opendict::v1::OpendictData>>,
+    /// 생성한 닉네임과 설명
+    nicknames: Vec<(String, Vec<String>)>,
+    nickname_list: widgets::List<'a>,
+    nickname_selected: widgets::ListState,
+}
+
+impl<'a> Data<'a> {
+    pub(super) fn new() -> Self {
+        Self {
+            items: None,
+            nicknames: Vec::new(),
+            nickname_list: widgets::List::default(),
+            nickname_selected: widgets::ListState::default(),
+        }
+    }
+    fn generate(&mut self) {
+        let items = self
+            .items
+            .get_or_insert_with(crate::prelude::get_opendict_items);
+        let pool = items.iter().collect::<Vec<_>>();
+        let option = crate::generator::opendict::GenerateOption {
+            count: COUNT,
+            ..Default::default()
+        };
+        let nicknames = crate::generator::opendict::generate(
+            &pool,
+            &[
+                crate::data_collector::opendict::Pos::형용사,
+                crate::data_collector::opendict::Pos::명사,
+            ],
+            &option,
+        );
+        let ranked = crate::generator::score::Scorer::default().rank(nicknames, &option);
+        self.nicknames = ranked
+            .iter()
+            .map(|x| {
+                let lines = crate::generator::explain::to_lines(&x.nickname);
+                (x.nickname.text.clone(), lines)
+            })
+            .collect();
+        self.nickname_list = self
+            .nicknames
+            .iter()
+            .map(|(nickname, _)| nickname.clone())
+            .collect::<widgets::List>()
+            .block(widgets::Block::bordered())
+            .highlight_style(Style::default().yellow());
+        self.nickname_selected
+            .select((!self.nicknames.is_empty()).then_some(0));
+    }
+}
+
+pub(super) fn draw(frame: &mut Frame, mut area: Rect, parent_ctx: &mut TuiContext) {
+    let ctx = &mut parent_ctx.generator;
+
+    let widget_area = Rect {
+        x: area.x,
+        y: area.y,
+        width: 24,
+        height: area.height - 1,
+    };
+    frame.render_stateful_widget(&ctx.nickname_list, widget_area, &mut ctx.nickname_selected);
+
+    let widget_area = Rect {
+        x: area.x + 25,
+        y: area.y,
+        width: area.width - 25,
+        height: area.height - 1,
+    };
+    let explanation = ctx
+        .nickname_selected
+        .selected()
+        .and_then(|x| ctx.nicknames.get(x))
+        .map(|(_, lines)| lines.join("\n"))
+        .unwrap_or_default();
+    let paragraph = widgets::Paragraph::new(explanation)
+        .wrap(widgets::Wrap { trim: false })
+        .block(widgets::Block::bordered());
+    frame.render_widget(paragraph, widget_area);
+
+    area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+    frame.render_widget(
+        widgets::Paragraph::new("Enter: 닉네임 생성, ↑↓: 닉네임 선택"),
+        area,
+    );
+}
+
+pub(super) fn pressed_event(parent_ctx: &mut TuiContext, pressed: KeyCode) {
+    let ctx = &mut parent_ctx.generator;
+
+    match pressed {
+        KeyCode::Enter => ctx.generate(),
+        KeyCode::Down => ctx.nickname_selected.select_next(),
+        KeyCode::Up => ctx.nickname_selected.select_previous(),
+        _ => {}
+    }
+}
@@ -10,7 +10,7 @@ pub(super) struct Data<'a> {
 }
 
 impl<'a> Data<'a> {
-    pub(super) fn new(opendict_item_codes: &Vec<u32>) -> Self {
+    pub(super) fn new(opendict_item_codes: &[u32]) -> Self {
         let item_codes = opendict_item_codes
             .iter()
             .map(|code| code.to_string())
@@ -24,13 +24,13 @@ impl<'a> Data<'a> {
             item_data: String::new(),
         }
     }
-    fn selected_with_arrow(&mut self, opendict_item_codes: &Vec<u32>) {
+    fn selected_with_arrow(&mut self, opendict_item_codes: &[u32]) {
         let Some(selected) = self.item_codes_selected.selected() else {
             return;
         };
         self.item_codes_inputted = opendict_item_codes[selected].to_string();
     }
-    fn selected_with_num(&mut self, opendict_item_codes: &Vec<u32>) {
+    fn selected_with_num(&mut self, opendict_item_codes: &[u32]) {
         let Ok(data): Result<u32, _> = self.item_codes_inputted.parse() else {
             return;
         };
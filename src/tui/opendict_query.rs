@@ -21,7 +21,7 @@ pub(super) struct Data<'a> {
 }
 
 impl<'a> Data<'a> {
-    pub(super) fn new(opendict_searched_word: &Vec<String>) -> Self {
+    pub(super) fn new(opendict_searched_word: &[String]) -> Self {
         let opendict_query_word = opendict_searched_word
             .iter()
             .cloned()
@@ -148,15 +148,11 @@ pub(super) fn pressed_event(parent_ctx: &mut TuiContext, pressed: KeyCode) {
         }
     };
     match pressed {
-        KeyCode::Right => {
-            if ctx.tab_cursor < 2 {
-                ctx.tab_cursor += 1;
-            }
+        KeyCode::Right if ctx.tab_cursor < 2 => {
+            ctx.tab_cursor += 1;
         }
-        KeyCode::Left => {
-            if ctx.tab_cursor > 0 {
-                ctx.tab_cursor -= 1;
-            }
+        KeyCode::Left if ctx.tab_cursor > 0 => {
+            ctx.tab_cursor -= 1;
         }
         KeyCode::Down => match ctx.tab_cursor {
             0 => {